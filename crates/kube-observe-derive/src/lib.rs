@@ -0,0 +1,113 @@
+//! Derive macros for kube-observe.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Ident, LitStr, parse_macro_input};
+
+const DEFAULT_PATH: &str = "status.conditions";
+
+/// Derives `kube_observe::HasStatusConditions`. See the re-export in `kube-observe` for details.
+#[proc_macro_derive(HasStatusConditions, attributes(conditions))]
+pub fn derive_has_status_conditions(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !matches!(input.data, Data::Struct(_)) {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "HasStatusConditions can only be derived for structs",
+        ));
+    }
+    let path = conditions_path(&input)?;
+    let (first, rest) = path.split_first().expect("path has at least one segment");
+
+    let read = rest.iter().fold(
+        quote!(self.#first.as_ref()),
+        |acc, segment| quote!(#acc.and_then(|value| value.#segment.as_ref())),
+    );
+    let remove = rest.iter().fold(
+        quote!(self.#first.as_mut()),
+        |acc, segment| quote!(#acc.and_then(|value| value.#segment.as_mut())),
+    );
+    let write = rest.iter().fold(
+        quote!(self.#first.get_or_insert_with(::core::default::Default::default)),
+        |acc, segment| quote!(#acc.#segment.get_or_insert_with(::core::default::Default::default)),
+    );
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::kube_observe::HasStatusConditions for #name #ty_generics #where_clause {
+            fn condition_opt(
+                &self,
+                type_: impl ::std::string::ToString,
+            ) -> ::core::option::Option<::kube_observe::__private::Condition> {
+                ::kube_observe::__private::condition_opt(#read, type_.to_string())
+            }
+
+            fn condition_types(&self) -> ::std::vec::Vec<::std::string::String> {
+                ::kube_observe::__private::condition_types(#read)
+            }
+
+            fn conditions_iter(
+                &self,
+            ) -> impl ::core::iter::Iterator<Item = ::kube_observe::__private::Condition> {
+                ::kube_observe::__private::conditions_iter(#read)
+            }
+
+            fn condition_mut(
+                &mut self,
+                type_: impl ::std::string::ToString,
+            ) -> impl ::std::ops::DerefMut<Target = ::kube_observe::__private::Condition> {
+                ::kube_observe::__private::condition_mut(#write, type_.to_string())
+            }
+
+            fn conditions_mut(
+                &mut self,
+            ) -> impl ::std::ops::DerefMut<
+                Target = ::std::vec::Vec<::kube_observe::__private::Condition>,
+            > {
+                #write
+            }
+
+            fn remove_condition(
+                &mut self,
+                type_: impl ::std::string::ToString,
+            ) -> ::core::option::Option<::kube_observe::__private::Condition> {
+                ::kube_observe::__private::remove_condition(#remove, type_.to_string())
+            }
+        }
+    })
+}
+
+/// Parses the `#[conditions(path = "...")]` attribute into the fields along the path.
+fn conditions_path(input: &DeriveInput) -> syn::Result<Vec<Ident>> {
+    let mut path = LitStr::new(DEFAULT_PATH, proc_macro2::Span::call_site());
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("conditions"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("path") {
+                path = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported conditions attribute"))
+            }
+        })?;
+    }
+
+    path.value()
+        .split('.')
+        .map(|segment| {
+            syn::parse_str::<Ident>(segment)
+                .map_err(|_| syn::Error::new_spanned(&path, format!("invalid field `{segment}`")))
+        })
+        .collect()
+}
@@ -0,0 +1,68 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube_observe::{ConditionExt, HasStatusConditions};
+
+#[derive(Default, HasStatusConditions)]
+struct Standard {
+    status: Option<StandardStatus>,
+}
+
+#[derive(Default)]
+struct StandardStatus {
+    conditions: Option<Vec<Condition>>,
+}
+
+#[derive(Default, HasStatusConditions)]
+#[conditions(path = "state.observed.conditions")]
+struct Nested {
+    state: Option<NestedState>,
+}
+
+#[derive(Default)]
+struct NestedState {
+    observed: Option<NestedObserved>,
+}
+
+#[derive(Default)]
+struct NestedObserved {
+    conditions: Option<Vec<Condition>>,
+}
+
+#[test]
+fn reads_and_writes_standard_path() {
+    let mut standard = Standard::default();
+    assert!(standard.condition("Ready").is_unknown());
+
+    standard.condition_mut("Ready").set_true();
+    standard.condition_mut("Available").set_false();
+    standard.condition_mut("Ready").set_reason("Reconciled");
+
+    assert!(standard.condition("Ready").is_true());
+    assert!(standard.condition("Ready").has_reason("Reconciled"));
+    assert!(standard.condition("Available").is_false());
+    assert!(standard.has_condition("Ready"));
+    assert_eq!(standard.condition_types(), ["Available", "Ready"]);
+
+    let types: Vec<_> = standard
+        .status
+        .unwrap()
+        .conditions
+        .unwrap()
+        .into_iter()
+        .map(|condition| condition.type_)
+        .collect();
+    assert_eq!(types, ["Available", "Ready"]);
+}
+
+#[test]
+fn reads_and_writes_custom_path() {
+    let mut nested = Nested::default();
+    nested.condition_mut("Ready").set_true();
+
+    assert!(nested.condition("Ready").is_true());
+    assert!(nested.remove_condition("Ready").is_some());
+    nested.condition_mut("Ready").set_true();
+    nested.conditions_mut()[0].set_reason("Reconciled");
+    assert!(nested.condition("Ready").has_reason("Reconciled"));
+    let conditions = nested.state.unwrap().observed.unwrap().conditions.unwrap();
+    assert_eq!(conditions.len(), 1);
+}
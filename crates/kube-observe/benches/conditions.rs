@@ -0,0 +1,49 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use k8s_openapi::api::core::v1::{Pod, PodCondition, PodStatus};
+use kube_observe::HasStatusConditions;
+use std::hint::black_box;
+
+fn pod_with_conditions(count: usize) -> Pod {
+    let conditions = (0..count)
+        .map(|index| PodCondition {
+            type_: format!("example.com/gate-{index}"),
+            status: "True".to_string(),
+            reason: Some("Ready".to_string()),
+            message: Some("the gate is ready".to_string()),
+            ..Default::default()
+        })
+        .collect();
+    Pod {
+        status: Some(PodStatus {
+            conditions: Some(conditions),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn pod_condition(c: &mut Criterion) {
+    let pod = pod_with_conditions(30);
+    let mut group = c.benchmark_group("pod_condition");
+
+    group.bench_function("borrowed", |b| {
+        b.iter(|| black_box(&pod).condition(black_box("example.com/gate-29")))
+    });
+
+    // The lookup as it would be done by cloning the whole status first, for comparison.
+    group.bench_function("cloned_status", |b| {
+        b.iter(|| {
+            let status = black_box(&pod).status.clone().unwrap_or_default();
+            status
+                .conditions
+                .unwrap_or_default()
+                .into_iter()
+                .find(|condition| condition.type_ == black_box("example.com/gate-29"))
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, pod_condition);
+criterion_main!(benches);
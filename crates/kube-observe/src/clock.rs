@@ -0,0 +1,92 @@
+//! The source of the current time used when stamping conditions.
+//!
+//! By default the system clock is used. The crate only asks for `chrono`'s `now` feature, which
+//! implies `std`, and `k8s-openapi` adds `alloc` and `serde`: those four are the only features of
+//! `chrono` that get built, so its local time zone support (`clock`) is never pulled in. Tests can
+//! pin the time with [`with_clock`]:
+//!
+//! ```
+//! use chrono::DateTime;
+//! use kube_observe::clock::{self, FixedClock};
+//!
+//! let now = clock::with_clock(FixedClock(DateTime::UNIX_EPOCH), clock::now);
+//! assert_eq!(now, DateTime::UNIX_EPOCH);
+//! ```
+
+use std::cell::RefCell;
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time.
+pub trait Clock {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// A clock that reads the system time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that always reports the same time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+thread_local! {
+    static CLOCK: RefCell<Option<Box<dyn Clock>>> = const { RefCell::new(None) };
+}
+
+/// The current time according to the clock in use on this thread.
+pub fn now() -> DateTime<Utc> {
+    CLOCK.with_borrow(|clock| match clock {
+        Some(clock) => clock.now(),
+        None => SystemClock.now(),
+    })
+}
+
+/// Runs `f` with `clock` as the source of the current time on this thread.
+///
+/// The previous clock is restored once `f` returns, even if it panics.
+pub fn with_clock<R>(clock: impl Clock + 'static, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Box<dyn Clock>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CLOCK.with_borrow_mut(|clock| *clock = previous);
+        }
+    }
+
+    let previous = CLOCK.with_borrow_mut(|current| current.replace(Box::new(clock)));
+    let _restore = Restore(previous);
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn nested_clocks_are_restored() {
+        let outer = DateTime::UNIX_EPOCH;
+        let inner = outer + Duration::hours(1);
+        with_clock(FixedClock(outer), || {
+            assert_eq!(with_clock(FixedClock(inner), now), inner);
+            assert_eq!(now(), outer);
+        });
+        assert!(now() > inner);
+    }
+}
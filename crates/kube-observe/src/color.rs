@@ -0,0 +1,45 @@
+//! Colored terminal rendering of conditions.
+
+use anstyle::{AnsiColor, Style};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use crate::{ConditionExt, ConditionStatus};
+
+/// Renders the condition like [`ConditionExt::display`], colored green if it is `True`, red if it
+/// is `False`, and yellow if it is `Unknown`.
+///
+/// Falls back to plain text if the `NO_COLOR` environment variable is set to a non-empty value.
+pub fn render_colored(condition: &Condition) -> String {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    render(condition, !no_color)
+}
+
+fn render(condition: &Condition, color: bool) -> String {
+    let text = condition.display().to_string();
+    if !color {
+        return text;
+    }
+
+    let color = match condition.status() {
+        ConditionStatus::True => AnsiColor::Green,
+        ConditionStatus::False => AnsiColor::Red,
+        ConditionStatus::Unknown => AnsiColor::Yellow,
+    };
+    let style = Style::new().fg_color(Some(color.into()));
+    format!("{}{text}{}", style.render(), style.render_reset())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_by_status() {
+        let condition = Condition::unknown("Ready").with_status(ConditionStatus::True);
+        assert_eq!(render(&condition, false), "Ready=True");
+        assert_eq!(render(&condition, true), "\x1b[32mReady=True\x1b[0m");
+
+        let condition = condition.with_status(ConditionStatus::False);
+        assert_eq!(render(&condition, true), "\x1b[31mReady=False\x1b[0m");
+    }
+}
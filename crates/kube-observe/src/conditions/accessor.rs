@@ -0,0 +1,132 @@
+use std::ops::DerefMut;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::{HasStatusConditions, list};
+
+/// Access to a list of [`Condition`]s stored on a resource, from which [`HasStatusConditions`] is
+/// implemented automatically.
+///
+/// This is the least a custom resource needs to implement. Both traits have `conditions` and
+/// `conditions_mut` methods, so calls to them are ambiguous when both traits are in scope; use
+/// fully qualified syntax such as `ConditionAccessor::conditions(&resource)` instead.
+///
+/// ```
+/// use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+/// use kube_observe::{ConditionAccessor, HasStatusConditions};
+///
+/// #[derive(Default)]
+/// struct MyStatus {
+///     conditions: Option<Vec<Condition>>,
+/// }
+///
+/// #[derive(Default)]
+/// struct MyResource {
+///     status: Option<MyStatus>,
+/// }
+///
+/// impl ConditionAccessor for MyResource {
+///     fn conditions(&self) -> Option<&Vec<Condition>> {
+///         self.status.as_ref()?.conditions.as_ref()
+///     }
+///
+///     fn conditions_mut(&mut self) -> &mut Vec<Condition> {
+///         self.status
+///             .get_or_insert_with(Default::default)
+///             .conditions
+///             .get_or_insert_with(Vec::new)
+///     }
+/// }
+///
+/// assert!(!MyResource::default().has_condition("Ready"));
+/// ```
+pub trait ConditionAccessor {
+    /// Returns the conditions of the resource, or `None` if it has none.
+    fn conditions(&self) -> Option<&Vec<Condition>>;
+
+    /// Returns the conditions of the resource, creating an empty list if it has none.
+    fn conditions_mut(&mut self) -> &mut Vec<Condition>;
+}
+
+impl<T: ConditionAccessor> HasStatusConditions for T {
+    fn condition_opt(&self, type_: impl ToString) -> Option<Condition> {
+        list::condition_opt(ConditionAccessor::conditions(self), type_.to_string())
+    }
+
+    fn condition_types(&self) -> Vec<String> {
+        list::condition_types(ConditionAccessor::conditions(self))
+    }
+
+    fn conditions_iter(&self) -> impl Iterator<Item = Condition> {
+        list::conditions_iter(ConditionAccessor::conditions(self))
+    }
+
+    fn condition_mut(&mut self, type_: impl ToString) -> impl DerefMut<Target = Condition> {
+        list::condition_mut(ConditionAccessor::conditions_mut(self), type_.to_string())
+    }
+
+    fn conditions_mut(&mut self) -> impl DerefMut<Target = Vec<Condition>> {
+        ConditionAccessor::conditions_mut(self)
+    }
+
+    fn remove_condition(&mut self, type_: impl ToString) -> Option<Condition> {
+        ConditionAccessor::conditions(self)?;
+        list::remove_condition(
+            Some(ConditionAccessor::conditions_mut(self)),
+            type_.to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kube::CustomResource;
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::ConditionExt;
+
+    #[derive(CustomResource, Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+    #[kube(
+        group = "kube-observe.test",
+        version = "v1",
+        kind = "Accessed",
+        status = "AccessedStatus"
+    )]
+    struct AccessedSpec {}
+
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+    struct AccessedStatus {
+        conditions: Option<Vec<Condition>>,
+    }
+
+    impl ConditionAccessor for Accessed {
+        fn conditions(&self) -> Option<&Vec<Condition>> {
+            self.status.as_ref()?.conditions.as_ref()
+        }
+
+        fn conditions_mut(&mut self) -> &mut Vec<Condition> {
+            self.status
+                .get_or_insert_with(Default::default)
+                .conditions
+                .get_or_insert_with(Vec::new)
+        }
+    }
+
+    #[test]
+    fn accessor_is_enough() {
+        let mut accessed = Accessed::new("accessed", AccessedSpec {});
+        assert!(accessed.condition("Ready").is_unknown());
+        assert_eq!(accessed.remove_condition("Ready"), None);
+        assert!(accessed.status.is_none());
+
+        accessed.condition_mut("Ready").set_true();
+        accessed.condition_mut("Available").set_false();
+        assert!(accessed.condition("Ready").is_true());
+        assert_eq!(accessed.condition_types(), ["Available", "Ready"]);
+
+        assert!(accessed.remove_condition("Available").unwrap().is_false());
+        assert_eq!(accessed.condition_types(), ["Ready"]);
+    }
+}
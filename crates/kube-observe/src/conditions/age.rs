@@ -0,0 +1,56 @@
+use chrono::Duration;
+
+/// A coarse classification of how long ago a condition transitioned, for display.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AgeBucket {
+    /// Less than a minute ago, or in the future.
+    JustNow,
+    /// At least a minute but less than an hour ago.
+    Minutes,
+    /// At least an hour but less than a day ago.
+    Hours,
+    /// At least a day ago.
+    Days,
+}
+
+impl AgeBucket {
+    /// Classifies an age.
+    pub fn from_age(age: Duration) -> Self {
+        if age < Duration::minutes(1) {
+            AgeBucket::JustNow
+        } else if age < Duration::hours(1) {
+            AgeBucket::Minutes
+        } else if age < Duration::days(1) {
+            AgeBucket::Hours
+        } else {
+            AgeBucket::Days
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{Condition, Time};
+
+    use super::*;
+    use crate::ConditionExt;
+
+    #[test]
+    fn buckets_representative_ages() {
+        let condition = Condition {
+            last_transition_time: Time(DateTime::UNIX_EPOCH),
+            ..Condition::unknown("Ready")
+        };
+        let bucket = |age| condition.age_bucket(DateTime::UNIX_EPOCH + age);
+
+        assert_eq!(bucket(Duration::seconds(-5)), AgeBucket::JustNow);
+        assert_eq!(bucket(Duration::seconds(59)), AgeBucket::JustNow);
+        assert_eq!(bucket(Duration::minutes(1)), AgeBucket::Minutes);
+        assert_eq!(bucket(Duration::minutes(59)), AgeBucket::Minutes);
+        assert_eq!(bucket(Duration::hours(1)), AgeBucket::Hours);
+        assert_eq!(bucket(Duration::hours(23)), AgeBucket::Hours);
+        assert_eq!(bucket(Duration::days(1)), AgeBucket::Days);
+        assert_eq!(bucket(Duration::days(400)), AgeBucket::Days);
+    }
+}
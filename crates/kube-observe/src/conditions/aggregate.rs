@@ -0,0 +1,191 @@
+use super::standard::{self, deployment, job, kstatus};
+use super::{ConditionExt, HasStatusConditions};
+
+/// A single verdict on whether a resource has finished reconciling, in the spirit of kstatus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AggregateStatus {
+    /// The resource has been fully reconciled.
+    Current,
+    /// The resource is still being reconciled.
+    InProgress,
+    /// The resource failed to reconcile.
+    Failed,
+    /// The resource does not report any of the conditions needed to reach a verdict.
+    Unknown,
+}
+
+/// The condition types and reasons used by [`aggregate_status_with`] to reach a verdict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AggregateConfig {
+    /// Condition types that mark the resource as reconciled when `True`.
+    pub ready_types: Vec<String>,
+    /// Condition types that mark the resource as being reconciled when `True`.
+    pub progressing_types: Vec<String>,
+    /// Reasons of a `True` progressing condition that mean the reconciliation has finished.
+    pub complete_reasons: Vec<String>,
+    /// Condition types that mark the resource as failed when `True`.
+    pub failed_types: Vec<String>,
+    /// Reasons of a `False` progressing condition that mean the resource failed.
+    pub failed_reasons: Vec<String>,
+}
+
+impl Default for AggregateConfig {
+    fn default() -> Self {
+        Self {
+            ready_types: vec![standard::READY.to_string(), standard::AVAILABLE.to_string()],
+            progressing_types: vec![
+                standard::PROGRESSING.to_string(),
+                kstatus::RECONCILING.to_string(),
+            ],
+            complete_reasons: vec![deployment::NEW_REPLICA_SET_AVAILABLE.to_string()],
+            failed_types: vec![
+                standard::DEGRADED.to_string(),
+                job::FAILED.to_string(),
+                kstatus::STALLED.to_string(),
+                deployment::REPLICA_FAILURE.to_string(),
+            ],
+            failed_reasons: vec![deployment::PROGRESS_DEADLINE_EXCEEDED.to_string()],
+        }
+    }
+}
+
+/// Collapses the conditions of a resource into a single verdict using the default rules.
+///
+/// See [`aggregate_status_with`] for how the verdict is reached.
+pub fn aggregate_status(resource: &impl HasStatusConditions) -> AggregateStatus {
+    aggregate_status_with(resource, &AggregateConfig::default())
+}
+
+/// Collapses the conditions of a resource into a single verdict.
+///
+/// The rules are applied in order:
+/// 1. any `True` failed condition, or `False` progressing condition with a failed reason, makes
+///    the resource [`Failed`](AggregateStatus::Failed);
+/// 2. any `True` progressing condition without a complete reason makes it
+///    [`InProgress`](AggregateStatus::InProgress);
+/// 3. if any ready conditions are present, the resource is [`Current`](AggregateStatus::Current)
+///    when they are all `True` and [`InProgress`](AggregateStatus::InProgress) otherwise;
+/// 4. otherwise the resource is [`Unknown`](AggregateStatus::Unknown).
+pub fn aggregate_status_with(
+    resource: &impl HasStatusConditions,
+    config: &AggregateConfig,
+) -> AggregateStatus {
+    if config
+        .failed_types
+        .iter()
+        .any(|type_| resource.condition(type_).is_true())
+    {
+        return AggregateStatus::Failed;
+    }
+    let stuck = config.progressing_types.iter().any(|type_| {
+        let condition = resource.condition(type_);
+        condition.is_false() && config.failed_reasons.contains(&condition.reason)
+    });
+    if stuck {
+        return AggregateStatus::Failed;
+    }
+
+    let progressing = config.progressing_types.iter().any(|type_| {
+        let condition = resource.condition(type_);
+        condition.is_true() && !config.complete_reasons.contains(&condition.reason)
+    });
+    if progressing {
+        return AggregateStatus::InProgress;
+    }
+
+    let mut ready = config
+        .ready_types
+        .iter()
+        .filter(|type_| resource.has_condition(type_))
+        .peekable();
+    if ready.peek().is_none() {
+        return AggregateStatus::Unknown;
+    }
+    if ready.all(|type_| resource.condition(type_).is_true()) {
+        AggregateStatus::Current
+    } else {
+        AggregateStatus::InProgress
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conditions::testing::{deployment, dummy};
+
+    #[test]
+    fn rolled_out_deployment_is_current() {
+        let deployment = deployment(&[
+            ("Available", "True", "MinimumReplicasAvailable"),
+            ("Progressing", "True", "NewReplicaSetAvailable"),
+        ]);
+        assert_eq!(aggregate_status(&deployment), AggregateStatus::Current);
+    }
+
+    #[test]
+    fn rolling_deployment_is_in_progress() {
+        let deployment = deployment(&[
+            ("Available", "True", "MinimumReplicasAvailable"),
+            ("Progressing", "True", "ReplicaSetUpdated"),
+        ]);
+        assert_eq!(aggregate_status(&deployment), AggregateStatus::InProgress);
+    }
+
+    #[test]
+    fn deployment_past_its_deadline_is_failed() {
+        let deployment = deployment(&[
+            ("Available", "True", "MinimumReplicasAvailable"),
+            ("Progressing", "False", "ProgressDeadlineExceeded"),
+        ]);
+        assert_eq!(aggregate_status(&deployment), AggregateStatus::Failed);
+    }
+
+    #[test]
+    fn failing_deployment_is_failed() {
+        let deployment = deployment(&[
+            ("Available", "False", "MinimumReplicasUnavailable"),
+            ("ReplicaFailure", "True", "FailedCreate"),
+        ]);
+        assert_eq!(aggregate_status(&deployment), AggregateStatus::Failed);
+    }
+
+    #[test]
+    fn custom_resource() {
+        let mut dummy = dummy();
+        assert_eq!(aggregate_status(&dummy), AggregateStatus::Unknown);
+
+        dummy.condition_mut("Ready").set_false();
+        assert_eq!(aggregate_status(&dummy), AggregateStatus::InProgress);
+
+        dummy.condition_mut("Ready").set_true();
+        assert_eq!(aggregate_status(&dummy), AggregateStatus::Current);
+
+        dummy.condition_mut("Degraded").set_true();
+        assert_eq!(aggregate_status(&dummy), AggregateStatus::Failed);
+    }
+
+    #[test]
+    fn custom_rules() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Synced").set_true();
+        dummy.condition_mut("Broken").set_true();
+
+        let config = AggregateConfig {
+            ready_types: vec!["Synced".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            aggregate_status_with(&dummy, &config),
+            AggregateStatus::Current
+        );
+
+        let config = AggregateConfig {
+            failed_types: vec!["Broken".to_string()],
+            ..config
+        };
+        assert_eq!(
+            aggregate_status_with(&dummy, &config),
+            AggregateStatus::Failed
+        );
+    }
+}
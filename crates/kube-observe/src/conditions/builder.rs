@@ -0,0 +1,101 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::{ConditionExt, ConditionStatus};
+
+/// Fluently constructs a [`Condition`].
+///
+/// Fields that are not set default to those of an `Unknown` condition with no reason or message.
+#[derive(Clone, Debug, Default)]
+pub struct ConditionBuilder {
+    type_: String,
+    status: Option<ConditionStatus>,
+    reason: Option<String>,
+    message: Option<String>,
+    observed_generation: Option<i64>,
+}
+
+impl ConditionBuilder {
+    /// Creates a builder with every field unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the type of the condition.
+    pub fn type_(mut self, type_: impl ToString) -> Self {
+        self.type_ = type_.to_string();
+        self
+    }
+
+    /// Sets the status of the condition.
+    pub fn status(mut self, status: ConditionStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the reason of the condition.
+    pub fn reason(mut self, reason: impl ToString) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    /// Sets the message of the condition.
+    pub fn message(mut self, message: impl ToString) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    /// Sets the observed generation of the condition.
+    pub fn observed_generation(mut self, generation: i64) -> Self {
+        self.observed_generation = Some(generation);
+        self
+    }
+
+    /// Builds the condition, stamping `last_transition_time` with the current time.
+    pub fn build(self) -> Condition {
+        let mut condition = Condition::unknown(self.type_);
+        if let Some(status) = self.status {
+            condition.status = status.to_string();
+        }
+        if let Some(reason) = self.reason {
+            condition.reason = reason;
+        }
+        if let Some(message) = self.message {
+            condition.message = message;
+        }
+        condition.observed_generation = self.observed_generation;
+        condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_fully_populated_condition() {
+        let condition = Condition::builder()
+            .type_("Ready")
+            .status(ConditionStatus::True)
+            .reason("Reconciled")
+            .message("all good")
+            .observed_generation(5)
+            .build();
+
+        assert_eq!(condition.type_, "Ready");
+        assert!(condition.is_true());
+        assert!(condition.has_reason("Reconciled"));
+        assert_eq!(condition.message, "all good");
+        assert_eq!(condition.observed_generation, Some(5));
+    }
+
+    #[test]
+    fn missing_fields_default_to_unknown() {
+        let condition = ConditionBuilder::new().type_("Ready").build();
+        let unknown = Condition::unknown("Ready");
+
+        assert_eq!(condition.status, unknown.status);
+        assert_eq!(condition.reason, unknown.reason);
+        assert_eq!(condition.message, unknown.message);
+        assert_eq!(condition.observed_generation, unknown.observed_generation);
+    }
+}
@@ -0,0 +1,68 @@
+use super::{ConditionExt, ConditionStatus, HasStatusConditions};
+
+/// How many resources report a condition of a given type with each status.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConditionCounts {
+    /// Resources whose condition is `True`.
+    pub true_: usize,
+    /// Resources whose condition is `False`.
+    pub false_: usize,
+    /// Resources whose condition is `Unknown` or missing.
+    pub unknown: usize,
+}
+
+impl ConditionCounts {
+    /// The total number of resources counted.
+    pub fn total(&self) -> usize {
+        self.true_ + self.false_ + self.unknown
+    }
+}
+
+/// Counts the statuses of the condition of the given type across several resources.
+///
+/// Resources that do not have the condition count as `Unknown`.
+pub fn count_condition<'a, R: HasStatusConditions + 'a>(
+    resources: impl IntoIterator<Item = &'a R>,
+    type_: impl ToString,
+) -> ConditionCounts {
+    let type_ = type_.to_string();
+    let mut counts = ConditionCounts::default();
+    for resource in resources {
+        match resource.condition(&type_).status() {
+            ConditionStatus::True => counts.true_ += 1,
+            ConditionStatus::False => counts.false_ += 1,
+            ConditionStatus::Unknown => counts.unknown += 1,
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conditions::standard::node;
+    use crate::conditions::testing;
+
+    #[test]
+    fn counts_node_readiness() {
+        let nodes = vec![
+            testing::node(&[("Ready", "True")]),
+            testing::node(&[("Ready", "True")]),
+            testing::node(&[("Ready", "False")]),
+            testing::node(&[("Ready", "Unknown")]),
+            testing::node(&[]),
+        ];
+
+        let counts = count_condition(&nodes, node::READY);
+        assert_eq!(
+            counts,
+            ConditionCounts {
+                true_: 2,
+                false_: 1,
+                unknown: 2,
+            }
+        );
+        assert_eq!(counts.total(), nodes.len());
+        assert_eq!(count_condition(&nodes[..0], node::READY).total(), 0);
+    }
+}
@@ -0,0 +1,82 @@
+use k8s_openapi::api::apps::v1::{DaemonSet, DaemonSetCondition};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+
+impl_has_status_conditions!(DaemonSet, status.conditions);
+
+/// DaemonSet conditions are not tied to a generation. The controller rarely sets any, so most
+/// lookups fall back to an `Unknown` condition.
+impl TypedCondition for DaemonSetCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: None,
+            last_transition_time: transition_time(self.last_transition_time.as_ref()),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut daemon_set_condition = DaemonSetCondition::default();
+        daemon_set_condition.write_back(condition);
+        daemon_set_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::apps::v1::DaemonSetStatus;
+
+    use super::*;
+    use crate::{ConditionExt, HasStatusConditions};
+
+    #[test]
+    fn reads_custom_condition() {
+        let daemon_set = DaemonSet {
+            status: Some(DaemonSetStatus {
+                conditions: Some(vec![DaemonSetCondition {
+                    type_: "example.com/RolloutComplete".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let condition = daemon_set.condition("example.com/RolloutComplete");
+        assert!(condition.is_true());
+        assert!(condition.observed_generation.is_none());
+        assert!(daemon_set.condition("Ready").is_unknown());
+    }
+
+    #[test]
+    fn missing_conditions_are_unknown() {
+        let daemon_set = DaemonSet {
+            status: Some(DaemonSetStatus::default()),
+            ..Default::default()
+        };
+
+        assert!(daemon_set.condition_types().is_empty());
+        assert!(
+            daemon_set
+                .condition("example.com/RolloutComplete")
+                .is_unknown()
+        );
+    }
+}
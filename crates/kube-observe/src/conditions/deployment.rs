@@ -0,0 +1,166 @@
+use k8s_openapi::api::apps::v1::{Deployment, DeploymentCondition};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::standard::deployment;
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+use super::{ConditionExt, HasStatusConditions};
+
+impl_has_status_conditions!(Deployment, status.conditions);
+
+/// Helpers for the standard conditions reported by the deployment controller.
+pub trait DeploymentConditionExt {
+    /// Whether the latest rollout has finished: the `Progressing` condition is `True` with reason
+    /// `NewReplicaSetAvailable` and the `Available` condition is `True`.
+    ///
+    /// A missing or `Unknown` condition means the rollout is not complete.
+    fn rollout_complete(&self) -> bool;
+}
+
+impl DeploymentConditionExt for Deployment {
+    fn rollout_complete(&self) -> bool {
+        let progressing = self.condition(deployment::PROGRESSING);
+        progressing.is_true()
+            && progressing.has_reason(deployment::NEW_REPLICA_SET_AVAILABLE)
+            && self.condition(deployment::AVAILABLE).is_true()
+    }
+}
+
+/// Deployment conditions are not tied to a generation. A missing `last_transition_time` falls back
+/// to `last_update_time`, and both are bumped together when writing back.
+impl TypedCondition for DeploymentCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        let time = self
+            .last_transition_time
+            .as_ref()
+            .or(self.last_update_time.as_ref());
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: None,
+            last_transition_time: transition_time(time),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+        self.last_update_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut deployment_condition = DeploymentCondition::default();
+        deployment_condition.write_back(condition);
+        deployment_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use k8s_openapi::api::apps::v1::DeploymentStatus;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    use super::*;
+    use crate::conditions::testing::dummy;
+
+    fn deployment() -> Deployment {
+        Deployment {
+            status: Some(DeploymentStatus {
+                conditions: Some(vec![
+                    DeploymentCondition {
+                        type_: "Available".to_string(),
+                        status: "True".to_string(),
+                        reason: Some("MinimumReplicasAvailable".to_string()),
+                        ..Default::default()
+                    },
+                    DeploymentCondition {
+                        type_: "Progressing".to_string(),
+                        status: "True".to_string(),
+                        reason: Some("NewReplicaSetAvailable".to_string()),
+                        last_update_time: Some(Time(DateTime::UNIX_EPOCH)),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn copies_conditions_into_custom_resource() {
+        let deployment = deployment();
+        let mut dummy = dummy();
+
+        assert!(dummy.copy_conditions_from(&deployment, &["Available", "Progressing", "Ready"]));
+        assert!(!dummy.copy_conditions_from(&deployment, &["Available", "Progressing", "Ready"]));
+
+        assert_eq!(dummy.condition_types(), ["Available", "Progressing"]);
+        let progressing = dummy.condition("Progressing");
+        assert!(progressing.is_true());
+        assert!(progressing.has_reason("NewReplicaSetAvailable"));
+    }
+
+    fn rollout(progressing: (&str, &str)) -> Deployment {
+        let mut deployment = deployment();
+        let mut condition = deployment.condition_mut("Progressing");
+        condition.status = progressing.0.to_string();
+        condition.reason = progressing.1.to_string();
+        drop(condition);
+        deployment
+    }
+
+    #[test]
+    fn rollout_completes_with_new_replica_set() {
+        assert!(deployment().rollout_complete());
+        assert!(!rollout(("True", "ReplicaSetUpdated")).rollout_complete());
+        assert!(!rollout(("False", "ProgressDeadlineExceeded")).rollout_complete());
+        assert!(!Deployment::default().rollout_complete());
+
+        let mut unavailable = deployment();
+        unavailable.condition_mut("Available").set_false();
+        assert!(!unavailable.rollout_complete());
+    }
+
+    #[test]
+    fn reads_standard_conditions() {
+        let deployment = deployment();
+
+        let available = deployment.condition("Available");
+        assert!(available.is_true());
+        assert!(available.has_reason("MinimumReplicasAvailable"));
+
+        let progressing = deployment.condition("Progressing");
+        assert!(progressing.is_true());
+        assert!(progressing.has_reason("NewReplicaSetAvailable"));
+        assert_eq!(progressing.last_transition_time, Time(DateTime::UNIX_EPOCH));
+
+        assert!(deployment.condition("ReplicaFailure").is_unknown());
+    }
+
+    #[test]
+    fn writes_back_conditions() {
+        let mut deployment = deployment();
+        deployment.condition_mut("Available").set_false();
+        deployment.condition_mut("ReplicaFailure").set_true();
+
+        assert!(deployment.condition("Available").is_false());
+        assert!(deployment.condition("ReplicaFailure").is_true());
+
+        let conditions = deployment.status.unwrap().conditions.unwrap();
+        assert_eq!(conditions.len(), 3);
+        assert_eq!(
+            conditions[0].last_update_time,
+            conditions[0].last_transition_time
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::{ConditionExt, HasStatusConditions};
+
+/// A change to a single condition between two versions of a resource.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConditionDiff {
+    /// The condition is only present in the new version.
+    Added(Condition),
+    /// The condition is only present in the old version.
+    Removed(Condition),
+    /// The condition is present in both versions but differs in more than its transition time.
+    Changed {
+        /// The condition in the old version.
+        previous: Condition,
+        /// The condition in the new version.
+        current: Condition,
+    },
+}
+
+impl ConditionDiff {
+    /// The type of the condition that changed.
+    pub fn type_(&self) -> &str {
+        match self {
+            ConditionDiff::Added(condition) | ConditionDiff::Removed(condition) => &condition.type_,
+            ConditionDiff::Changed { current, .. } => &current.type_,
+        }
+    }
+}
+
+/// Lists the conditions that differ between two versions of a resource.
+///
+/// Conditions whose only difference is their `last_transition_time` are not reported, as with
+/// [`ConditionExt::semantically_eq`]. Changed and removed conditions are listed in the order they
+/// are stored in `old`, followed by added conditions in the order they are stored in `new`.
+pub fn diff_conditions(
+    old: &impl HasStatusConditions,
+    new: &impl HasStatusConditions,
+) -> Vec<ConditionDiff> {
+    let mut diffs: Vec<_> = old
+        .conditions()
+        .into_iter()
+        .filter_map(|previous| match new.condition_opt(&previous.type_) {
+            None => Some(ConditionDiff::Removed(previous)),
+            Some(current) if !previous.semantically_eq(&current) => {
+                Some(ConditionDiff::Changed { previous, current })
+            }
+            Some(_) => None,
+        })
+        .collect();
+    diffs.extend(
+        new.conditions()
+            .into_iter()
+            .filter(|current| !old.has_condition(&current.type_))
+            .map(ConditionDiff::Added),
+    );
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conditions::testing::{ReadyReasons, dummy, epoch};
+
+    #[test]
+    fn diffs_before_and_after() {
+        let mut before = dummy();
+        before.condition_mut("Ready").set_false();
+        before.condition_mut("Available").set_true();
+        before.condition_mut("Legacy").set_true();
+
+        let mut after = before.clone();
+        after.condition_mut("Ready").set_reason(ReadyReasons::Yoyo);
+        after.condition_mut("Available").last_transition_time = epoch();
+        let legacy = after.remove_condition("Legacy").unwrap();
+        after.condition_mut("Synced").set_true();
+
+        let diffs = diff_conditions(&before, &after);
+        let types: Vec<_> = diffs.iter().map(ConditionDiff::type_).collect();
+        assert_eq!(types, ["Legacy", "Ready", "Synced"]);
+
+        assert_eq!(diffs[0], ConditionDiff::Removed(legacy));
+        let ConditionDiff::Changed { previous, current } = &diffs[1] else {
+            panic!("expected a change, got {:?}", diffs[1]);
+        };
+        assert!(previous.has_reason(""));
+        assert!(current.has_reason(ReadyReasons::Yoyo));
+        assert!(matches!(&diffs[2], ConditionDiff::Added(synced) if synced.is_true()));
+
+        assert!(diff_conditions(&after, &after).is_empty());
+    }
+}
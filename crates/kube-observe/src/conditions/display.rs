@@ -0,0 +1,53 @@
+use std::fmt;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+/// Formats a condition as a compact, human readable string, such as
+/// `Ready=True (Reconciled): all good [gen 5]`.
+///
+/// The reason, message, and observed generation are omitted when the condition has none. Create
+/// one with [`ConditionExt::display`](super::ConditionExt::display).
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayCondition<'a>(pub &'a Condition);
+
+impl fmt::Display for DisplayCondition<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let condition = self.0;
+        write!(f, "{}={}", condition.type_, condition.status)?;
+        if !condition.reason.is_empty() {
+            write!(f, " ({})", condition.reason)?;
+        }
+        if !condition.message.is_empty() {
+            write!(f, ": {}", condition.message)?;
+        }
+        if let Some(generation) = condition.observed_generation {
+            write!(f, " [gen {generation}]")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ConditionExt, ConditionStatus};
+
+    use super::*;
+
+    #[test]
+    fn formats_compactly() {
+        let condition = Condition::unknown("Ready");
+        assert_eq!(condition.display().to_string(), "Ready=Unknown");
+
+        let condition = Condition {
+            observed_generation: Some(5),
+            ..condition
+                .with_status(ConditionStatus::True)
+                .with_reason("Reconciled")
+                .with_message("all good")
+        };
+        assert_eq!(
+            condition.display().to_string(),
+            "Ready=True (Reconciled): all good [gen 5]"
+        );
+    }
+}
@@ -0,0 +1,371 @@
+use std::ops::{Deref, DerefMut};
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube::core::DynamicObject;
+use serde_json::{Map, Value};
+
+use super::typed::transition_time;
+use super::{ConditionExt, HasStatusConditions};
+
+/// Conditions are read from `data.status.conditions`. Entries that are not conditions are skipped,
+/// and kept as is when the conditions are written back, as are any fields of the entries that are not
+/// part of a [`Condition`]. Missing `reason`, `message`, and `lastTransitionTime` fields are
+/// tolerated, since not every custom resource sets them.
+impl HasStatusConditions for DynamicObject {
+    fn condition_opt(&self, type_: impl ToString) -> Option<Condition> {
+        let type_ = type_.to_string();
+        entries(&self.data)
+            .iter()
+            .filter_map(parse)
+            .find(|condition| condition.type_ == type_)
+    }
+
+    fn condition_types(&self) -> Vec<String> {
+        entries(&self.data)
+            .iter()
+            .filter_map(parse)
+            .map(|condition| condition.type_)
+            .collect()
+    }
+
+    fn conditions_iter(&self) -> impl Iterator<Item = Condition> {
+        entries(&self.data).iter().filter_map(parse)
+    }
+
+    fn condition_mut(&mut self, type_: impl ToString) -> impl DerefMut<Target = Condition> {
+        let type_ = type_.to_string();
+        let entries = entries_mut(&mut self.data);
+        let index = match entries
+            .iter()
+            .position(|entry| parse(entry).is_some_and(|condition| condition.type_ == type_))
+        {
+            Some(index) => index,
+            None => {
+                entries.push(Value::Object(Map::new()));
+                entries.len() - 1
+            }
+        };
+        DynamicConditionMut::new(&mut entries[index], type_)
+    }
+
+    fn conditions_mut(&mut self) -> impl DerefMut<Target = Vec<Condition>> {
+        DynamicConditionsMut::new(entries_mut(&mut self.data))
+    }
+
+    fn remove_condition(&mut self, type_: impl ToString) -> Option<Condition> {
+        let type_ = type_.to_string();
+        let entries = self
+            .data
+            .get_mut("status")?
+            .get_mut("conditions")?
+            .as_array_mut()?;
+        let (index, condition) = entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| Some((index, parse(entry)?)))
+            .find(|(_, condition)| condition.type_ == type_)?;
+        entries.remove(index);
+        Some(condition)
+    }
+}
+
+/// The entries of the conditions array of the object, empty if there is none.
+fn entries(data: &Value) -> &[Value] {
+    data.get("status")
+        .and_then(|status| status.get("conditions"))
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+/// The entries of the conditions array of the object, replacing anything in the way with an empty
+/// object or array.
+fn entries_mut(data: &mut Value) -> &mut Vec<Value> {
+    let status = object_mut(data)
+        .entry("status")
+        .or_insert_with(|| Value::Object(Map::new()));
+    let conditions = object_mut(status)
+        .entry("conditions")
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if !conditions.is_array() {
+        *conditions = Value::Array(Vec::new());
+    }
+    conditions.as_array_mut().expect("replaced above")
+}
+
+fn object_mut(value: &mut Value) -> &mut Map<String, Value> {
+    if !value.is_object() {
+        *value = Value::Object(Map::new());
+    }
+    value.as_object_mut().expect("replaced above")
+}
+
+/// Parses an entry of the conditions array, or `None` if it is not a condition.
+fn parse(entry: &Value) -> Option<Condition> {
+    let mut entry = entry.as_object()?.clone();
+    for field in ["reason", "message"] {
+        entry
+            .entry(field)
+            .or_insert_with(|| Value::String(String::new()));
+    }
+    if !entry.contains_key("lastTransitionTime") {
+        let time = serde_json::to_value(transition_time(None)).ok()?;
+        entry.insert("lastTransitionTime".to_string(), time);
+    }
+    serde_json::from_value::<Condition>(Value::Object(entry))
+        .ok()
+        .filter(|condition| !condition.type_.is_empty())
+}
+
+/// Writes a condition into an entry of the conditions array, keeping any fields that are not part
+/// of a [`Condition`].
+fn write(entry: &mut Value, condition: &Condition) {
+    let Ok(Value::Object(fields)) = serde_json::to_value(condition) else {
+        return;
+    };
+    let entry = object_mut(entry);
+    entry.remove("observedGeneration");
+    entry.extend(fields);
+}
+
+/// A view of an entry of the conditions array that writes any changes back to it when dropped.
+struct DynamicConditionMut<'a> {
+    target: &'a mut Value,
+    original: Option<Condition>,
+    condition: Condition,
+}
+
+impl<'a> DynamicConditionMut<'a> {
+    /// `target` must either parse as a condition of type `type_`, or be a fresh entry.
+    fn new(target: &'a mut Value, type_: String) -> Self {
+        let original = parse(target);
+        Self {
+            condition: original
+                .clone()
+                .unwrap_or_else(|| Condition::unknown(type_)),
+            original,
+            target,
+        }
+    }
+}
+
+impl Deref for DynamicConditionMut<'_> {
+    type Target = Condition;
+
+    fn deref(&self) -> &Condition {
+        &self.condition
+    }
+}
+
+impl DerefMut for DynamicConditionMut<'_> {
+    fn deref_mut(&mut self) -> &mut Condition {
+        &mut self.condition
+    }
+}
+
+impl Drop for DynamicConditionMut<'_> {
+    fn drop(&mut self) {
+        if self.original.as_ref() != Some(&self.condition) {
+            write(self.target, &self.condition);
+        }
+    }
+}
+
+/// A view of the conditions array that writes any changes back to it when dropped.
+///
+/// Entries that are still present keep any extra fields, and are only written back if they
+/// changed. Entries that are not conditions are kept at their position in the array.
+struct DynamicConditionsMut<'a> {
+    target: &'a mut Vec<Value>,
+    /// The position in `target` of each condition in `original`.
+    indices: Vec<usize>,
+    original: Vec<Condition>,
+    conditions: Vec<Condition>,
+}
+
+impl<'a> DynamicConditionsMut<'a> {
+    fn new(target: &'a mut Vec<Value>) -> Self {
+        let (indices, original): (Vec<_>, Vec<_>) = target
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| Some((index, parse(entry)?)))
+            .unzip();
+        Self {
+            conditions: original.clone(),
+            indices,
+            original,
+            target,
+        }
+    }
+}
+
+impl Deref for DynamicConditionsMut<'_> {
+    type Target = Vec<Condition>;
+
+    fn deref(&self) -> &Vec<Condition> {
+        &self.conditions
+    }
+}
+
+impl DerefMut for DynamicConditionsMut<'_> {
+    fn deref_mut(&mut self) -> &mut Vec<Condition> {
+        &mut self.conditions
+    }
+}
+
+impl Drop for DynamicConditionsMut<'_> {
+    fn drop(&mut self) {
+        if self.conditions == self.original {
+            return;
+        }
+        let mut previous: Vec<_> = std::mem::take(self.target).into_iter().map(Some).collect();
+        for condition in &self.conditions {
+            let matched = self
+                .original
+                .iter()
+                .zip(&self.indices)
+                .position(|(original, &index)| {
+                    original.type_ == condition.type_ && previous[index].is_some()
+                });
+            let entry = match matched {
+                Some(matched) => {
+                    let mut entry = previous[self.indices[matched]]
+                        .take()
+                        .expect("checked above");
+                    if self.original[matched] != *condition {
+                        write(&mut entry, condition);
+                    }
+                    entry
+                }
+                None => {
+                    let mut entry = Value::Object(Map::new());
+                    write(&mut entry, condition);
+                    entry
+                }
+            };
+            self.target.push(entry);
+        }
+        for (index, entry) in previous.into_iter().enumerate() {
+            if let Some(entry) = entry.filter(|_| !self.indices.contains(&index)) {
+                self.target.insert(index.min(self.target.len()), entry);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kube::core::{ApiResource, GroupVersionKind};
+    use serde_json::json;
+
+    use super::*;
+
+    fn object(conditions: Value) -> DynamicObject {
+        let resource = ApiResource::from_gvk(&GroupVersionKind::gvk("example.com", "v1", "Widget"));
+        DynamicObject::new("widget", &resource).data(json!({
+            "status": { "conditions": conditions },
+        }))
+    }
+
+    #[test]
+    fn reads_conditions_and_skips_malformed_entries() {
+        let object = object(json!([
+            {
+                "type": "Ready",
+                "status": "True",
+                "reason": "Reconciled",
+                "message": "all good",
+                "lastTransitionTime": "2024-01-01T00:00:00Z",
+            },
+            "not a condition",
+            { "status": "False" },
+            { "type": "Synced", "status": "False" },
+        ]));
+
+        assert_eq!(object.condition_types(), ["Ready", "Synced"]);
+        let ready = object.condition("Ready");
+        assert!(ready.is_true());
+        assert!(ready.has_reason("Reconciled"));
+        assert!(object.condition("Synced").is_false());
+        assert!(object.condition("Available").is_unknown());
+    }
+
+    #[test]
+    fn writes_back_into_json() {
+        let mut object = object(json!([
+            { "type": "Ready", "status": "False", "reason": "Pending", "extra": 1 },
+            "not a condition",
+        ]));
+
+        object.condition_mut("Ready").set_true();
+        object.condition_mut("Synced").set_reason("Waiting");
+
+        let entries = object.data["status"]["conditions"].as_array().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["status"], "True");
+        assert_eq!(entries[0]["extra"], 1);
+        assert_eq!(entries[1], "not a condition");
+        assert_eq!(entries[2]["type"], "Synced");
+        assert_eq!(entries[2]["reason"], "Waiting");
+        assert!(object.condition("Ready").is_true());
+    }
+
+    #[test]
+    fn rewrites_whole_list() {
+        let mut object = object(json!([
+            "not a condition",
+            { "type": "Ready", "status": "True", "extra": 1 },
+            { "type": "Legacy", "status": "True" },
+        ]));
+
+        {
+            let mut conditions = object.conditions_mut();
+            conditions.retain(|condition| condition.type_ != "Legacy");
+            conditions.push(Condition::unknown("Synced"));
+        }
+
+        let entries = object.data["status"]["conditions"].as_array().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0], "not a condition");
+        assert_eq!(
+            entries[1],
+            json!({ "type": "Ready", "status": "True", "extra": 1 })
+        );
+        assert_eq!(entries[2]["type"], "Synced");
+
+        assert_eq!(object.remove_condition("Ready").unwrap().type_, "Ready");
+        assert_eq!(object.condition_types(), ["Synced"]);
+    }
+
+    #[test]
+    fn leaves_unchanged_entries_untouched() {
+        let mut object = object(json!([
+            { "type": "Ready", "status": "True" },
+            "not a condition",
+            { "type": "Synced", "status": "False" },
+        ]));
+
+        {
+            let mut conditions = object.conditions_mut();
+            conditions[1].set_true();
+        }
+
+        let entries = object.data["status"]["conditions"].as_array().unwrap();
+        assert_eq!(entries[0], json!({ "type": "Ready", "status": "True" }));
+        assert_eq!(entries[1], "not a condition");
+        assert_eq!(entries[2]["type"], "Synced");
+        assert_eq!(entries[2]["status"], "True");
+        assert!(entries[2].get("lastTransitionTime").is_some());
+    }
+
+    #[test]
+    fn creates_missing_status() {
+        let mut object = object(Value::Null);
+        object.data = json!({ "spec": {} });
+        assert!(object.condition("Ready").is_unknown());
+        assert_eq!(object.remove_condition("Ready"), None);
+
+        object.condition_mut("Ready").set_true();
+        assert_eq!(object.data["status"]["conditions"][0]["status"], "True");
+        assert!(object.data["spec"].is_object());
+    }
+}
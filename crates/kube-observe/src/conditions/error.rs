@@ -0,0 +1,51 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error raised while accessing the conditions of a resource.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConditionError {
+    /// The resource does not support mutating its conditions.
+    Unsupported,
+}
+
+impl fmt::Display for ConditionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConditionError::Unsupported => {
+                f.write_str("resource does not support mutating its conditions")
+            }
+        }
+    }
+}
+
+impl Error for ConditionError {}
+
+/// A problem that makes a condition invalid according to the kubernetes API conventions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConditionValidationError {
+    /// The type of the condition is empty.
+    EmptyType,
+    /// The status of the condition is not one of `True`, `False`, or `Unknown`.
+    NonCanonicalStatus(String),
+    /// The condition has a known status but no reason.
+    MissingReason,
+}
+
+impl fmt::Display for ConditionValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConditionValidationError::EmptyType => f.write_str("condition type is empty"),
+            ConditionValidationError::NonCanonicalStatus(status) => {
+                write!(
+                    f,
+                    "condition status `{status}` is not True, False, or Unknown"
+                )
+            }
+            ConditionValidationError::MissingReason => {
+                f.write_str("condition has a known status but no reason")
+            }
+        }
+    }
+}
+
+impl Error for ConditionValidationError {}
@@ -0,0 +1,202 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::standard::{self, deployment};
+use super::{ConditionExt, HasStatusConditions};
+
+/// Whether a workload is serving as intended, in the style of the `Available`, `Progressing`, and
+/// `Degraded` conditions of Deployments.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkloadHealth {
+    /// The workload is available and not rolling out.
+    Healthy,
+    /// The workload is rolling out, or has not reported enough to tell.
+    Progressing,
+    /// The workload is degraded, unavailable, or its rollout is stuck.
+    Degraded {
+        /// The reason of the condition that marked the workload as degraded.
+        reason: String,
+        /// The message of the condition that marked the workload as degraded.
+        message: String,
+    },
+}
+
+impl WorkloadHealth {
+    fn degraded(condition: Condition) -> Self {
+        WorkloadHealth::Degraded {
+            reason: condition.reason,
+            message: condition.message,
+        }
+    }
+}
+
+/// The condition types and reasons used by [`workload_health_with`] to judge a workload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkloadHealthConfig {
+    /// Condition types that must all be `True` for the workload to be healthy.
+    pub available_types: Vec<String>,
+    /// Condition types that mark the workload as rolling out when `True`, and as stuck when `False`.
+    pub progressing_types: Vec<String>,
+    /// Reasons of a `True` progressing condition that mean the rollout has finished.
+    pub complete_reasons: Vec<String>,
+    /// Condition types that mark the workload as degraded when `True`.
+    pub degraded_types: Vec<String>,
+}
+
+impl Default for WorkloadHealthConfig {
+    fn default() -> Self {
+        Self {
+            available_types: vec![standard::AVAILABLE.to_string()],
+            progressing_types: vec![standard::PROGRESSING.to_string()],
+            complete_reasons: vec![deployment::NEW_REPLICA_SET_AVAILABLE.to_string()],
+            degraded_types: vec![
+                standard::DEGRADED.to_string(),
+                deployment::REPLICA_FAILURE.to_string(),
+            ],
+        }
+    }
+}
+
+/// Judges the health of a workload using the standard condition types.
+///
+/// See [`workload_health_with`] for how the health is judged.
+pub fn workload_health(resource: &impl HasStatusConditions) -> WorkloadHealth {
+    workload_health_with(resource, &WorkloadHealthConfig::default())
+}
+
+/// Judges the health of a workload.
+///
+/// The rules are applied in order:
+/// 1. any `True` degraded condition, or `False` progressing condition, makes the workload
+///    [`Degraded`](WorkloadHealth::Degraded) with the reason and message of that condition;
+/// 2. any `True` progressing condition without a complete reason makes it
+///    [`Progressing`](WorkloadHealth::Progressing);
+/// 3. if every available condition is `True`, the workload is [`Healthy`](WorkloadHealth::Healthy);
+/// 4. any `False` available condition makes it [`Degraded`](WorkloadHealth::Degraded);
+/// 5. otherwise the workload is [`Progressing`](WorkloadHealth::Progressing).
+pub fn workload_health_with(
+    resource: &impl HasStatusConditions,
+    config: &WorkloadHealthConfig,
+) -> WorkloadHealth {
+    let degraded = config
+        .degraded_types
+        .iter()
+        .map(|type_| resource.condition(type_))
+        .find(ConditionExt::is_true);
+    if let Some(condition) = degraded {
+        return WorkloadHealth::degraded(condition);
+    }
+
+    let progressing: Vec<_> = config
+        .progressing_types
+        .iter()
+        .map(|type_| resource.condition(type_))
+        .collect();
+    if let Some(stuck) = progressing.iter().find(|condition| condition.is_false()) {
+        return WorkloadHealth::degraded(stuck.clone());
+    }
+    if progressing.iter().any(|condition| {
+        condition.is_true() && !config.complete_reasons.contains(&condition.reason)
+    }) {
+        return WorkloadHealth::Progressing;
+    }
+
+    let available: Vec<_> = config
+        .available_types
+        .iter()
+        .map(|type_| resource.condition(type_))
+        .collect();
+    if available.iter().all(ConditionExt::is_true) {
+        return WorkloadHealth::Healthy;
+    }
+    match available.into_iter().find(ConditionExt::is_false) {
+        Some(unavailable) => WorkloadHealth::degraded(unavailable),
+        None => WorkloadHealth::Progressing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conditions::testing::{deployment, dummy};
+
+    #[test]
+    fn rolled_out_deployment_is_healthy() {
+        let deployment = deployment(&[
+            ("Available", "True", "MinimumReplicasAvailable"),
+            ("Progressing", "True", "NewReplicaSetAvailable"),
+        ]);
+        assert_eq!(workload_health(&deployment), WorkloadHealth::Healthy);
+    }
+
+    #[test]
+    fn mid_rollout_deployment_is_progressing() {
+        let deployment = deployment(&[
+            ("Available", "True", "MinimumReplicasAvailable"),
+            ("Progressing", "True", "ReplicaSetUpdated"),
+        ]);
+        assert_eq!(workload_health(&deployment), WorkloadHealth::Progressing);
+    }
+
+    #[test]
+    fn stuck_deployment_is_degraded() {
+        let deployment = deployment(&[
+            ("Available", "True", "MinimumReplicasAvailable"),
+            ("Progressing", "False", "ProgressDeadlineExceeded"),
+        ]);
+        assert_eq!(
+            workload_health(&deployment),
+            WorkloadHealth::Degraded {
+                reason: "ProgressDeadlineExceeded".to_string(),
+                message: "Progressing is False".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn failing_deployment_is_degraded() {
+        let deployment = deployment(&[
+            ("Available", "False", "MinimumReplicasUnavailable"),
+            ("ReplicaFailure", "True", "FailedCreate"),
+        ]);
+        assert_eq!(
+            workload_health(&deployment),
+            WorkloadHealth::Degraded {
+                reason: "FailedCreate".to_string(),
+                message: "ReplicaFailure is True".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn custom_types() {
+        let mut dummy = dummy();
+        let config = WorkloadHealthConfig {
+            available_types: vec!["Serving".to_string()],
+            degraded_types: vec!["Unhealthy".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            workload_health_with(&dummy, &config),
+            WorkloadHealth::Progressing
+        );
+
+        dummy.condition_mut("Serving").set_true();
+        assert_eq!(
+            workload_health_with(&dummy, &config),
+            WorkloadHealth::Healthy
+        );
+
+        dummy.condition_mut("Unhealthy").set_all(
+            crate::ConditionStatus::True,
+            "Crashing",
+            "backing off",
+        );
+        assert_eq!(
+            workload_health_with(&dummy, &config),
+            WorkloadHealth::Degraded {
+                reason: "Crashing".to_string(),
+                message: "backing off".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use kube::Resource;
+use serde_json::{Value, json};
+
+use super::HasStatusConditions;
+
+/// The prefix of the annotations written by [`record_condition_history`].
+pub const HISTORY_ANNOTATION_PREFIX: &str = "kube-observe.io/history-";
+
+/// Appends a snapshot of the status, reason, and transition time of the condition of the given type
+/// to a JSON array stored in the `kube-observe.io/history-<type>` annotation of the resource,
+/// keeping only the last `max_entries` snapshots.
+///
+/// Any `/` in the type is replaced with `.` to form a valid annotation key. Nothing is recorded if
+/// the condition has not transitioned since the last snapshot, so this can be called on every
+/// reconcile. Nothing is recorded either if the resource has no condition of that type, or if
+/// `max_entries` is `0`, in which case any existing annotation is left untouched. An annotation
+/// that does not hold a JSON array is replaced. Returns whether a snapshot was recorded.
+pub fn record_condition_history<K: Resource + HasStatusConditions>(
+    resource: &mut K,
+    type_: &str,
+    max_entries: usize,
+) -> bool {
+    if max_entries == 0 {
+        return false;
+    }
+    let Some(condition) = resource.condition_opt(type_) else {
+        return false;
+    };
+    let snapshot = json!({
+        "status": condition.status,
+        "reason": condition.reason,
+        "lastTransitionTime": condition.last_transition_time,
+    });
+
+    let key = format!("{HISTORY_ANNOTATION_PREFIX}{}", type_.replace('/', "."));
+    let annotations = resource
+        .meta_mut()
+        .annotations
+        .get_or_insert_with(Default::default);
+    let mut history = annotations
+        .get(&key)
+        .and_then(|history| serde_json::from_str::<Vec<Value>>(history).ok())
+        .unwrap_or_default();
+    if history.last() == Some(&snapshot) {
+        return false;
+    }
+
+    history.push(snapshot);
+    let excess = history.len().saturating_sub(max_entries);
+    history.drain(..excess);
+    annotations.insert(key, Value::Array(history).to_string());
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration};
+
+    use super::*;
+    use crate::ConditionExt;
+    use crate::clock::{self, FixedClock};
+    use crate::conditions::testing::dummy;
+
+    #[test]
+    fn keeps_last_transitions() {
+        let mut dummy = dummy();
+        for (minute, reason) in ["First", "Second", "Third"].into_iter().enumerate() {
+            let now = DateTime::UNIX_EPOCH + Duration::minutes(minute as i64);
+            clock::with_clock(FixedClock(now), || {
+                dummy.condition_mut("example.com/Ready").set_reason(reason);
+            });
+            assert!(record_condition_history(&mut dummy, "example.com/Ready", 2));
+        }
+        assert!(!record_condition_history(
+            &mut dummy,
+            "example.com/Ready",
+            2
+        ));
+
+        let annotations = dummy.metadata.annotations.unwrap();
+        let history: Vec<Value> =
+            serde_json::from_str(&annotations["kube-observe.io/history-example.com.Ready"])
+                .unwrap();
+        assert_eq!(
+            history,
+            [
+                json!({
+                    "status": "Unknown",
+                    "reason": "Second",
+                    "lastTransitionTime": "1970-01-01T00:01:00Z",
+                }),
+                json!({
+                    "status": "Unknown",
+                    "reason": "Third",
+                    "lastTransitionTime": "1970-01-01T00:02:00Z",
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_nothing_without_entries() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+        assert!(!record_condition_history(&mut dummy, "Ready", 0));
+        assert!(!record_condition_history(&mut dummy, "Ready", 0));
+        assert!(dummy.metadata.annotations.is_none());
+    }
+
+    #[test]
+    fn skips_missing_condition() {
+        let mut dummy = dummy();
+        assert!(!record_condition_history(&mut dummy, "Ready", 2));
+        assert!(!record_condition_history(&mut dummy, "Ready", 2));
+        assert!(dummy.metadata.annotations.is_none());
+    }
+}
@@ -0,0 +1,81 @@
+use k8s_openapi::api::autoscaling::v2::{
+    HorizontalPodAutoscaler, HorizontalPodAutoscalerCondition,
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+
+impl_has_status_conditions!(HorizontalPodAutoscaler, status.conditions);
+
+/// Autoscaler conditions are not tied to a generation.
+impl TypedCondition for HorizontalPodAutoscalerCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: None,
+            last_transition_time: transition_time(self.last_transition_time.as_ref()),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut autoscaler_condition = HorizontalPodAutoscalerCondition::default();
+        autoscaler_condition.write_back(condition);
+        autoscaler_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::autoscaling::v2::HorizontalPodAutoscalerStatus;
+
+    use super::*;
+    use crate::conditions::standard::horizontal_pod_autoscaler;
+    use crate::{ConditionExt, ConditionStatus, HasStatusConditions};
+
+    #[test]
+    fn reads_scaling_limited_reason() {
+        let autoscaler = HorizontalPodAutoscaler {
+            status: Some(HorizontalPodAutoscalerStatus {
+                conditions: Some(vec![HorizontalPodAutoscalerCondition {
+                    type_: "ScalingLimited".to_string(),
+                    status: "True".to_string(),
+                    reason: Some("TooManyReplicas".to_string()),
+                    message: Some(
+                        "the desired replica count is more than the maximum replica count"
+                            .to_string(),
+                    ),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let condition = autoscaler.condition(horizontal_pod_autoscaler::SCALING_LIMITED);
+        assert!(condition.matches(ConditionStatus::True, "TooManyReplicas"));
+        assert_eq!(
+            condition.message,
+            "the desired replica count is more than the maximum replica count"
+        );
+        assert!(
+            autoscaler
+                .condition(horizontal_pod_autoscaler::ABLE_TO_SCALE)
+                .is_unknown()
+        );
+    }
+}
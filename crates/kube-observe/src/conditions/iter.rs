@@ -0,0 +1,63 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube::Resource;
+
+use super::{ConditionExt, ConditionStatus};
+
+/// Filters for iterators over conditions, such as
+/// [`HasStatusConditions::conditions_iter`](super::HasStatusConditions::conditions_iter).
+pub trait ConditionIterExt: Iterator<Item = Condition> + Sized {
+    /// Keeps only the conditions with the given status.
+    fn only_status(self, status: ConditionStatus) -> impl Iterator<Item = Condition> {
+        self.filter(move |condition| condition.status() == status)
+    }
+
+    /// Keeps only the conditions observed against the current generation of `resource`.
+    fn only_current(self, resource: &impl Resource) -> impl Iterator<Item = Condition> {
+        self.filter(move |condition| condition.is_current(resource))
+    }
+}
+
+impl<I: Iterator<Item = Condition>> ConditionIterExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HasStatusConditions;
+    use crate::conditions::testing::{dummy, node};
+
+    #[test]
+    fn filters_false_node_conditions() {
+        let node = node(&[
+            ("Ready", "True"),
+            ("MemoryPressure", "False"),
+            ("DiskPressure", "False"),
+            ("PIDPressure", "Unknown"),
+        ]);
+
+        let types: Vec<_> = node
+            .conditions_iter()
+            .only_status(ConditionStatus::False)
+            .map(|condition| condition.type_)
+            .collect();
+        assert_eq!(types, ["MemoryPressure", "DiskPressure"]);
+    }
+
+    #[test]
+    fn filters_current_conditions() {
+        let resource = dummy();
+        let conditions = [
+            Condition {
+                observed_generation: Some(3),
+                ..Condition::unknown("Ready")
+            },
+            Condition {
+                observed_generation: Some(2),
+                ..Condition::unknown("Available")
+            },
+        ];
+
+        let current: Vec<_> = conditions.into_iter().only_current(&resource).collect();
+        assert_eq!(current.len(), 1);
+        assert_eq!(current[0].type_, "Ready");
+    }
+}
@@ -0,0 +1,151 @@
+use k8s_openapi::api::batch::v1::{Job, JobCondition};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::standard::job;
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+use super::{ConditionExt, HasStatusConditions};
+
+impl_has_status_conditions!(
+    /// Mutating conditions is supported, but the built-in condition types such as `Complete` and
+    /// `Failed` are managed by the job controller, which may overwrite any changes made to them.
+    Job,
+    status.conditions
+);
+
+/// Helpers for the standard conditions reported by the job controller.
+///
+/// A missing or `Unknown` condition is never reported as `true`.
+pub trait JobConditionExt {
+    /// Whether the `Complete` condition is `True`, meaning the job succeeded.
+    fn is_complete(&self) -> bool;
+
+    /// Whether the `Failed` condition is `True`.
+    fn is_failed(&self) -> bool;
+
+    /// Whether the job has either completed or failed.
+    fn is_finished(&self) -> bool;
+}
+
+impl JobConditionExt for Job {
+    fn is_complete(&self) -> bool {
+        self.condition(job::COMPLETE).is_true()
+    }
+
+    fn is_failed(&self) -> bool {
+        self.condition(job::FAILED).is_true()
+    }
+
+    fn is_finished(&self) -> bool {
+        self.is_complete() || self.is_failed()
+    }
+}
+
+/// Job conditions are not tied to a generation. A missing `last_transition_time` falls back to
+/// `last_probe_time`, which is otherwise preserved when writing back.
+impl TypedCondition for JobCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        let time = self
+            .last_transition_time
+            .as_ref()
+            .or(self.last_probe_time.as_ref());
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: None,
+            last_transition_time: transition_time(time),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut job_condition = JobCondition::default();
+        job_condition.write_back(condition);
+        job_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use k8s_openapi::api::batch::v1::JobStatus;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    use super::*;
+
+    fn job(conditions: &[(&str, &str)]) -> Job {
+        Job {
+            status: Some(JobStatus {
+                conditions: Some(
+                    conditions
+                        .iter()
+                        .map(|(type_, status)| JobCondition {
+                            type_: type_.to_string(),
+                            status: status.to_string(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn succeeded_job_is_complete() {
+        let job = job(&[("SuccessCriteriaMet", "True"), ("Complete", "True")]);
+        assert!(job.is_complete());
+        assert!(!job.is_failed());
+        assert!(job.is_finished());
+    }
+
+    #[test]
+    fn failed_job_is_failed() {
+        let job = job(&[("FailureTarget", "True"), ("Failed", "True")]);
+        assert!(!job.is_complete());
+        assert!(job.is_failed());
+        assert!(job.is_finished());
+    }
+
+    #[test]
+    fn running_job_is_not_finished() {
+        let job = job(&[("Suspended", "False")]);
+        assert!(!job.is_complete());
+        assert!(!job.is_failed());
+        assert!(!job.is_finished());
+    }
+
+    #[test]
+    fn reads_complete_condition() {
+        let job = Job {
+            status: Some(JobStatus {
+                conditions: Some(vec![JobCondition {
+                    type_: "Complete".to_string(),
+                    status: "True".to_string(),
+                    last_probe_time: Some(Time(DateTime::UNIX_EPOCH)),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let complete = job.condition("Complete");
+        assert!(complete.is_true());
+        assert_eq!(complete.last_transition_time, Time(DateTime::UNIX_EPOCH));
+        assert!(job.condition("Failed").is_unknown());
+    }
+}
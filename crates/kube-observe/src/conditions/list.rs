@@ -0,0 +1,92 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::ConditionExt;
+
+/// Returns the condition of the given type from a list of conditions, if present.
+pub fn condition_opt(conditions: Option<&Vec<Condition>>, type_: String) -> Option<Condition> {
+    conditions?
+        .iter()
+        .find(|condition| condition.type_ == type_)
+        .cloned()
+}
+
+/// Iterates over a list of conditions, cloning them one at a time.
+pub fn conditions_iter(conditions: Option<&Vec<Condition>>) -> impl Iterator<Item = Condition> {
+    conditions.into_iter().flatten().cloned()
+}
+
+/// Returns the types of a list of conditions.
+pub fn condition_types(conditions: Option<&Vec<Condition>>) -> Vec<String> {
+    conditions
+        .into_iter()
+        .flatten()
+        .map(|condition| condition.type_.clone())
+        .collect()
+}
+
+/// Returns a mutable reference to the condition of the given type from a list of conditions,
+/// inserting an `Unknown` condition if there is none.
+///
+/// New conditions are inserted in order of type, so a list that is sorted by type stays sorted.
+/// Existing conditions are never replaced.
+pub fn condition_mut(conditions: &mut Vec<Condition>, type_: String) -> &mut Condition {
+    let index = match conditions
+        .iter()
+        .position(|condition| condition.type_ == type_)
+    {
+        Some(index) => index,
+        None => {
+            let index = conditions.partition_point(|condition| condition.type_ < type_);
+            conditions.insert(index, Condition::unknown(type_));
+            index
+        }
+    };
+    &mut conditions[index]
+}
+
+/// Removes the condition of the given type from a list of conditions, returning it if it was
+/// present.
+pub fn remove_condition(
+    conditions: Option<&mut Vec<Condition>>,
+    type_: String,
+) -> Option<Condition> {
+    let conditions = conditions?;
+    let index = conditions
+        .iter()
+        .position(|condition| condition.type_ == type_)?;
+    Some(conditions.remove(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn existing_condition_survives() {
+        let mut conditions = vec![
+            Condition::unknown("Available"),
+            Condition {
+                reason: "Reconciled".to_string(),
+                ..Condition::unknown("Ready")
+            },
+        ];
+
+        assert!(condition_mut(&mut conditions, "Ready".to_string()).has_reason("Reconciled"));
+        assert_eq!(conditions.len(), 2);
+        assert!(conditions[1].has_reason("Reconciled"));
+    }
+
+    #[test]
+    fn inserts_in_type_order() {
+        let mut conditions = Vec::new();
+        for type_ in ["Ready", "Available", "Progressing"] {
+            condition_mut(&mut conditions, type_.to_string());
+        }
+
+        let types: Vec<_> = conditions
+            .iter()
+            .map(|condition| condition.type_.as_str())
+            .collect();
+        assert_eq!(types, ["Available", "Progressing", "Ready"]);
+    }
+}
@@ -0,0 +1,10 @@
+/// How [`ConditionExt::merge`](super::ConditionExt::merge) picks between two conditions of the
+/// same type reported by independent signals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MergePrecedence {
+    /// The condition that transitioned last wins, keeping its transition time.
+    MostRecent,
+    /// The condition with the worst status wins, where `False` is worse than `Unknown`, which is
+    /// worse than `True`.
+    WorstStatus,
+}
@@ -0,0 +1,2310 @@
+//! Reading and updating the status conditions of kubernetes resources.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::ops::DerefMut;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{Condition, Time};
+use kube::Resource;
+
+use crate::clock;
+
+mod accessor;
+mod age;
+mod aggregate;
+mod builder;
+mod count;
+mod daemon_set;
+mod deployment;
+mod diff;
+mod display;
+mod dynamic_object;
+mod error;
+mod health;
+mod history;
+mod horizontal_pod_autoscaler;
+mod iter;
+mod job;
+pub(crate) mod list;
+mod merge;
+mod node;
+mod persistent_volume_claim;
+mod pod;
+mod pod_disruption_budget;
+mod policy;
+mod reason;
+mod replica_set;
+mod service;
+mod set;
+pub mod standard;
+mod stateful_set;
+mod status;
+mod status_map;
+mod table;
+#[cfg(test)]
+pub(crate) mod testing;
+mod tracked;
+mod typed;
+
+pub use accessor::ConditionAccessor;
+pub use age::AgeBucket;
+pub use aggregate::{AggregateConfig, AggregateStatus, aggregate_status, aggregate_status_with};
+pub use builder::ConditionBuilder;
+pub use count::{ConditionCounts, count_condition};
+pub use deployment::DeploymentConditionExt;
+pub use diff::{ConditionDiff, diff_conditions};
+pub use display::DisplayCondition;
+pub use error::{ConditionError, ConditionValidationError};
+pub use health::{WorkloadHealth, WorkloadHealthConfig, workload_health, workload_health_with};
+pub use history::{HISTORY_ANNOTATION_PREFIX, record_condition_history};
+pub use iter::ConditionIterExt;
+pub use job::JobConditionExt;
+pub use merge::MergePrecedence;
+pub use node::NodeConditionExt;
+pub use pod::PodConditionExt;
+pub use policy::{ConditionPolicy, is_healthy};
+pub use reason::ConditionReason;
+pub use set::ConditionSet;
+pub use status::ConditionStatus;
+pub use status_map::{conditions_from_status_map, conditions_to_status_map};
+pub use table::render_condition_table;
+pub use tracked::TrackedCondition;
+
+/// A resource that reports its state through a list of status conditions.
+pub trait HasStatusConditions {
+    /// Whether the resource supports [`condition_mut`](Self::condition_mut) and
+    /// [`conditions_mut`](Self::conditions_mut).
+    ///
+    /// Resources that set this to `false` panic in both; use [`TryHasStatusConditions`] to handle
+    /// them gracefully.
+    const SUPPORTS_CONDITION_MUT: bool = true;
+
+    /// Returns the condition of the given type, or `None` if the resource does not have one.
+    ///
+    /// Unlike [`condition`](Self::condition), this distinguishes a stored `Unknown` condition from
+    /// a missing one.
+    fn condition_opt(&self, type_: impl ToString) -> Option<Condition>;
+
+    /// Returns the condition of the given type.
+    ///
+    /// If the resource does not have a condition of that type, an `Unknown` condition is returned instead.
+    fn condition(&self, type_: impl ToString) -> Condition {
+        self.condition_or_else(type_, Condition::unknown)
+    }
+
+    /// Returns the condition of the given type, or the condition built by `default` from the type if
+    /// the resource does not have one.
+    ///
+    /// This generalizes [`condition`](Self::condition) to defaults other than a bare `Unknown`
+    /// condition, such as one with a reason. The factory is only called if the condition is absent.
+    fn condition_or_else(
+        &self,
+        type_: impl ToString,
+        default: impl FnOnce(String) -> Condition,
+    ) -> Condition {
+        let type_ = type_.to_string();
+        self.condition_opt(&type_).unwrap_or_else(|| default(type_))
+    }
+
+    /// The types of the conditions stored on the resource, in the order they are stored.
+    ///
+    /// Unlike [`condition`](Self::condition), this only reflects conditions that are actually present.
+    fn condition_types(&self) -> Vec<String>;
+
+    /// Whether the resource has a condition of the given type.
+    fn has_condition(&self, type_: impl ToString) -> bool {
+        let type_ = type_.to_string();
+        self.condition_types().contains(&type_)
+    }
+
+    /// Whether every condition of the given types is `True`.
+    ///
+    /// Missing conditions are `Unknown`, so this is `false` if any of the types is absent.
+    fn all_true(&self, types: &[&str]) -> bool {
+        types.iter().all(|type_| self.condition(type_).is_true())
+    }
+
+    /// Whether any condition of the given types is `False`.
+    ///
+    /// Missing conditions are `Unknown`, so absent types never count.
+    fn any_false(&self, types: &[&str]) -> bool {
+        types.iter().any(|type_| self.condition(type_).is_false())
+    }
+
+    /// Every condition stored on the resource, converted to a generic [`Condition`] in the order
+    /// they are stored.
+    fn conditions(&self) -> Vec<Condition> {
+        self.conditions_iter().collect()
+    }
+
+    /// Returns the worst of the conditions of the given types, where `False` is worse than
+    /// `Unknown`, which is worse than `True`.
+    ///
+    /// Missing conditions count as `Unknown`. Among equally bad conditions, the one that transitioned
+    /// last wins. If `types` is empty, an `Unknown` condition with an empty type is returned.
+    fn worst_condition(&self, types: &[&str]) -> Condition {
+        self.worst_condition_by(types, std::cmp::Reverse)
+    }
+
+    /// Returns the worst of the conditions of the given types according to `severity`, where a
+    /// higher severity is worse.
+    ///
+    /// Missing conditions count as `Unknown`. Among equally bad conditions, the one that transitioned
+    /// last wins. If `types` is empty, an `Unknown` condition with an empty type is returned.
+    fn worst_condition_by<S: Ord>(
+        &self,
+        types: &[&str],
+        severity: impl Fn(ConditionStatus) -> S,
+    ) -> Condition {
+        types
+            .iter()
+            .map(|type_| self.condition(type_))
+            .max_by(|a, b| {
+                severity(a.status())
+                    .cmp(&severity(b.status()))
+                    .then_with(|| a.last_transition_time.cmp(&b.last_transition_time))
+            })
+            .unwrap_or_else(|| Condition::unknown(""))
+    }
+
+    /// Synthesizes a top-level `Ready` condition from the conditions of the given types.
+    ///
+    /// The result is `True` if every dependency is `True`, `False` if any is `False`, and `Unknown`
+    /// otherwise. Missing dependencies count as `Unknown`. When not `True`, the message names the
+    /// dependencies holding it back, along with their reasons.
+    ///
+    /// The result is meant to be passed to [`set_condition`](Self::set_condition), which only bumps
+    /// `last_transition_time` if the synthesized condition differs from the stored one.
+    fn compute_ready(&self, depends_on: &[&str]) -> Condition {
+        let dependencies: Vec<_> = depends_on
+            .iter()
+            .map(|type_| self.condition(type_))
+            .collect();
+        let (status, reason) = if dependencies.iter().all(ConditionExt::is_true) {
+            (ConditionStatus::True, "DependenciesReady")
+        } else if dependencies.iter().any(ConditionExt::is_false) {
+            (ConditionStatus::False, "DependenciesNotReady")
+        } else {
+            (ConditionStatus::Unknown, "DependenciesUnknown")
+        };
+        let message = dependencies
+            .iter()
+            .filter(|dependency| dependency.status() == status && status != ConditionStatus::True)
+            .map(|dependency| match dependency.reason.as_str() {
+                "" => format!("{} is {status}", dependency.type_),
+                reason => format!("{} is {status} ({reason})", dependency.type_),
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        Condition::unknown("Ready")
+            .with_status(status)
+            .with_reason(reason)
+            .with_message(message)
+    }
+
+    /// Lazily iterates over the conditions stored on the resource, in the order they are stored.
+    ///
+    /// Combine it with the filters of [`ConditionIterExt`] to build read pipelines. The default
+    /// implementation looks up every type returned by [`condition_types`](Self::condition_types),
+    /// so implementations should walk the stored list directly instead.
+    fn conditions_iter(&self) -> impl Iterator<Item = Condition> {
+        self.condition_types()
+            .into_iter()
+            .filter_map(|type_| self.condition_opt(type_))
+    }
+
+    /// The conditions stored on the resource as a [`ConditionSet`], ordered by type rather than by
+    /// the order they are stored in.
+    fn conditions_sorted(&self) -> ConditionSet {
+        self.conditions().into()
+    }
+
+    /// Returns a mutable reference to the condition of the given type.
+    ///
+    /// If the resource does not have a condition of that type, an `Unknown` condition is inserted first.
+    /// Resources that do not store their conditions as [`Condition`]s return a guard that writes any
+    /// changes back to the underlying condition when dropped.
+    fn condition_mut(&mut self, type_: impl ToString) -> impl DerefMut<Target = Condition>;
+
+    /// Returns a mutable reference to the condition of the given type, first inserting an `Unknown`
+    /// condition with `default_reason` if there is none.
+    ///
+    /// An existing condition is returned untouched, including its `last_transition_time`.
+    fn ensure_condition(
+        &mut self,
+        type_: impl ToString,
+        default_reason: impl ToString,
+    ) -> impl DerefMut<Target = Condition> {
+        let type_ = type_.to_string();
+        let exists = self.has_condition(&type_);
+        let mut condition = self.condition_mut(type_);
+        if !exists {
+            condition.reason = default_reason.to_string();
+        }
+        condition
+    }
+
+    /// Seeds every missing condition of the given types as `Unknown` with `reason`, like
+    /// [`ensure_condition`](Self::ensure_condition), leaving existing conditions untouched.
+    ///
+    /// Returns whether any condition was inserted.
+    fn initialize_standard_conditions(&mut self, types: &[&str], reason: &str) -> bool {
+        let mut inserted = false;
+        for type_ in types {
+            if !self.has_condition(type_) {
+                self.ensure_condition(type_, reason);
+                inserted = true;
+            }
+        }
+        inserted
+    }
+
+    /// Returns a mutable reference to every condition stored on the resource, in the order they are
+    /// stored.
+    ///
+    /// Resources that do not store their conditions as [`Condition`]s return a guard that writes any
+    /// changes back to the underlying conditions when dropped. Unlike the setters of
+    /// [`ConditionExt`], changes made directly to the list do not bump `last_transition_time`.
+    fn conditions_mut(&mut self) -> impl DerefMut<Target = Vec<Condition>>;
+
+    /// Returns a mutable reference to the condition of the given type, like
+    /// [`condition_mut`](Self::condition_mut), that keeps its observed generation in sync with
+    /// `resource`.
+    ///
+    /// If the condition changed by the time the reference is dropped, its observed generation is set
+    /// to the current generation of `resource`, so there is no need to call
+    /// [`ConditionExt::set_generation_from`] separately.
+    fn condition_mut_for(
+        &mut self,
+        resource: &impl Resource,
+        type_: impl ToString,
+    ) -> TrackedCondition<impl DerefMut<Target = Condition>> {
+        let generation = resource.meta().generation;
+        TrackedCondition::new(self.condition_mut(type_), generation)
+    }
+
+    /// Returns a mutable reference to the condition of the given type that keeps its observed
+    /// generation in sync with the resource itself.
+    ///
+    /// This is [`condition_mut_for`](Self::condition_mut_for) for resources that store their own
+    /// conditions, which is almost all of them.
+    fn condition_mut_tracked(
+        &mut self,
+        type_: impl ToString,
+    ) -> TrackedCondition<impl DerefMut<Target = Condition>>
+    where
+        Self: Resource,
+    {
+        let generation = self.meta().generation;
+        TrackedCondition::new(self.condition_mut(type_), generation)
+    }
+
+    /// Removes the condition of the given type from the resource, returning it if it was present.
+    fn remove_condition(&mut self, type_: impl ToString) -> Option<Condition>;
+
+    /// Inserts the condition, replacing any existing condition of the same type.
+    ///
+    /// The `last_transition_time` of the given condition is ignored. Instead, the stored condition
+    /// is bumped to the current time only if its status, reason, message, or observed generation
+    /// changed. Returns whether the stored condition changed.
+    fn set_condition(&mut self, condition: Condition) -> bool {
+        let mut current = self.condition_mut(&condition.type_);
+        update_condition(&mut current, |current| {
+            current.status = condition.status;
+            current.reason = condition.reason;
+            current.message = condition.message;
+            current.observed_generation = condition.observed_generation;
+        })
+    }
+
+    /// Inserts the condition only if the resource has no condition of the same type, returning
+    /// whether it was inserted.
+    ///
+    /// This leaves conditions managed by other controllers untouched. As with
+    /// [`set_condition`](Self::set_condition), the inserted condition is stamped with the current
+    /// time.
+    fn set_condition_if_absent(&mut self, condition: Condition) -> bool {
+        if self.has_condition(&condition.type_) {
+            return false;
+        }
+        self.set_condition(condition);
+        true
+    }
+
+    /// Copies the status, reason, and message of the given condition types from `source`, returning
+    /// whether any condition changed.
+    ///
+    /// Types that `source` does not have are skipped. As with the setters of [`ConditionExt`], a
+    /// copied condition only transitions if it differs from the one already stored.
+    fn copy_conditions_from(&mut self, source: &impl HasStatusConditions, types: &[&str]) -> bool {
+        let mut changed = false;
+        for type_ in types {
+            let Some(condition) = source.condition_opt(type_) else {
+                continue;
+            };
+            changed |= self.condition_mut(type_).set_all(
+                condition.status(),
+                condition.reason,
+                condition.message,
+            );
+        }
+        changed
+    }
+
+    /// Sets the status, reason, and message of every condition in `conditions`, keyed by type,
+    /// returning the types that transitioned.
+    fn apply_conditions(
+        &mut self,
+        conditions: &BTreeMap<String, (ConditionStatus, String, String)>,
+    ) -> BTreeSet<String> {
+        let mut changed = BTreeSet::new();
+        for (type_, (status, reason, message)) in conditions {
+            if self.condition_mut(type_).set_all(*status, reason, message) {
+                changed.insert(type_.clone());
+            }
+        }
+        changed
+    }
+
+    /// Removes every condition observed against a generation older than the current generation of
+    /// `resource`, returning the removed conditions.
+    ///
+    /// Conditions without an observed generation are kept, since they do not track generations.
+    fn prune_stale(&mut self, resource: &impl Resource) -> Vec<Condition> {
+        let Some(generation) = resource.meta().generation else {
+            return Vec::new();
+        };
+        let stale: Vec<_> = self
+            .condition_types()
+            .into_iter()
+            .filter(|type_| {
+                self.condition(type_)
+                    .observed_generation
+                    .is_some_and(|observed| observed < generation)
+            })
+            .collect();
+        stale
+            .into_iter()
+            .filter_map(|type_| self.remove_condition(type_))
+            .collect()
+    }
+
+    /// Returns the types of the conditions that were not observed against the current generation of
+    /// `resource`, in the order they are stored.
+    ///
+    /// Unlike [`prune_stale`](Self::prune_stale), conditions without an observed generation are
+    /// reported too, since nothing shows they reflect the current generation.
+    fn stale_conditions(&self, resource: &impl Resource) -> Vec<String> {
+        self.conditions()
+            .into_iter()
+            .filter(|condition| {
+                condition.observed_generation.is_none() || !condition.is_current(resource)
+            })
+            .map(|condition| condition.type_)
+            .collect()
+    }
+
+    /// Removes every condition whose type is not in `keep`, leaving the others in their current
+    /// order.
+    ///
+    /// Panics if the resource does not [support](Self::SUPPORTS_CONDITION_MUT) mutating its
+    /// conditions.
+    fn retain_condition_types(&mut self, keep: &[&str]) {
+        self.conditions_mut()
+            .retain(|condition| keep.contains(&condition.type_.as_str()));
+    }
+}
+
+/// Fallible access to the conditions of a resource that may not support mutating them.
+pub trait TryHasStatusConditions {
+    /// Returns a mutable reference to the condition of the given type, like
+    /// [`HasStatusConditions::condition_mut`].
+    ///
+    /// Returns [`ConditionError::Unsupported`] instead of panicking if the resource does not support it.
+    fn try_condition_mut(
+        &mut self,
+        type_: impl ToString,
+    ) -> Result<impl DerefMut<Target = Condition>, ConditionError>;
+
+    /// Returns a mutable reference to every condition, like
+    /// [`HasStatusConditions::conditions_mut`].
+    ///
+    /// Returns [`ConditionError::Unsupported`] instead of panicking if the resource does not support it.
+    fn try_conditions_mut(
+        &mut self,
+    ) -> Result<impl DerefMut<Target = Vec<Condition>>, ConditionError>;
+}
+
+impl<T: HasStatusConditions> TryHasStatusConditions for T {
+    fn try_condition_mut(
+        &mut self,
+        type_: impl ToString,
+    ) -> Result<impl DerefMut<Target = Condition>, ConditionError> {
+        if !T::SUPPORTS_CONDITION_MUT {
+            return Err(ConditionError::Unsupported);
+        }
+        Ok(self.condition_mut(type_))
+    }
+
+    fn try_conditions_mut(
+        &mut self,
+    ) -> Result<impl DerefMut<Target = Vec<Condition>>, ConditionError> {
+        if !T::SUPPORTS_CONDITION_MUT {
+            return Err(ConditionError::Unsupported);
+        }
+        Ok(self.conditions_mut())
+    }
+}
+
+/// Predicates and setters for a single [`Condition`].
+///
+/// Every setter returns `true` if the condition changed, in which case `last_transition_time` is also bumped.
+/// Setting a field to the value it already has is a no-op and returns `false`.
+pub trait ConditionExt {
+    /// Starts building a new condition.
+    fn builder() -> ConditionBuilder
+    where
+        Self: Sized;
+
+    /// Creates a condition of the given type with an `Unknown` status and no reason or message.
+    ///
+    /// `last_transition_time` is stamped with [`clock::now`], so tests can pin it with
+    /// [`clock::with_clock`].
+    fn unknown(type_: impl ToString) -> Condition
+    where
+        Self: Sized;
+
+    /// The status of the condition.
+    ///
+    /// Any status other than `True` or `False` is reported as `Unknown`.
+    fn status(&self) -> ConditionStatus;
+
+    /// Whether the status of the condition is exactly the given string.
+    ///
+    /// Unlike [`status`](Self::status), this can match non-standard statuses used by some resources.
+    fn is_status(&self, status: impl ToString) -> bool;
+
+    /// Whether the status of the condition is `True`.
+    fn is_true(&self) -> bool;
+
+    /// Whether the status of the condition is `False`.
+    fn is_false(&self) -> bool;
+
+    /// Whether the status of the condition is `Unknown`.
+    ///
+    /// Like [`status`](Self::status), this treats any non-standard status as `Unknown`.
+    fn is_unknown(&self) -> bool;
+
+    /// The reason of the condition, empty if it has none.
+    fn reason(&self) -> &str;
+
+    /// The message of the condition, empty if it has none.
+    fn message(&self) -> &str;
+
+    /// When the condition last transitioned.
+    fn transitioned_at(&self) -> DateTime<Utc>;
+
+    /// The reason of the condition, or `default` if it has none.
+    fn reason_or(&self, default: &str) -> String;
+
+    /// The message of the condition, or `default` if it has none.
+    fn message_or(&self, default: &str) -> String;
+
+    /// Whether the status of the condition is healthier than that of `other`, where `True` is
+    /// healthier than `Unknown`, which is healthier than `False`.
+    fn is_healthier_than(&self, other: &Condition) -> bool;
+
+    /// Formats the condition as a compact, human readable string for logs.
+    fn display(&self) -> DisplayCondition<'_>;
+
+    /// The status of the condition as a boolean, or `None` if it is `Unknown`.
+    fn as_bool(&self) -> Option<bool>;
+
+    /// Whether the condition has the given reason.
+    fn has_reason(&self, reason: impl ToString) -> bool;
+
+    /// Parses the reason of the condition into a typed reason, or `None` if it does not parse.
+    fn reason_is<R: ConditionReason + FromStr>(&self) -> Option<R>;
+
+    /// Whether the condition has the given status and reason.
+    fn matches(&self, status: ConditionStatus, reason: impl ToString) -> bool;
+
+    /// Whether the condition has the given status, reason, and message, where `None` matches anything.
+    ///
+    /// The message is compared for exact equality; use [`message_contains`](Self::message_contains)
+    /// to match a substring.
+    fn matches_all(
+        &self,
+        status: Option<ConditionStatus>,
+        reason: Option<String>,
+        message: Option<String>,
+    ) -> bool;
+
+    /// Whether the message of the condition contains `needle`, compared case-sensitively.
+    fn message_contains(&self, needle: &str) -> bool;
+
+    /// Whether the message of the condition matches `regex` anywhere.
+    #[cfg(feature = "regex")]
+    fn message_matches(&self, regex: &regex::Regex) -> bool;
+
+    /// Checks that the condition follows the kubernetes API conventions: its type is not empty, its
+    /// status is one of `True`, `False`, or `Unknown`, and it has a reason unless it is `Unknown`.
+    ///
+    /// Only the first problem found is reported.
+    fn validate(&self) -> Result<(), ConditionValidationError>;
+
+    /// Whether the condition is equal to `other` in every field except `last_transition_time`.
+    ///
+    /// These are the same fields the setters compare to decide whether a condition transitioned.
+    fn semantically_eq(&self, other: &Condition) -> bool;
+
+    /// Whether setting the given status, reason, and message would transition the condition, using
+    /// the same comparison as the setters.
+    ///
+    /// The observed generation is only compared if `observed_generation` is `Some`.
+    fn needs_update(
+        &self,
+        status: ConditionStatus,
+        reason: &str,
+        message: &str,
+        observed_generation: Option<i64>,
+    ) -> bool;
+
+    /// Whether the condition was observed against the current generation of the resource.
+    fn is_current(&self, resource: &impl Resource) -> bool;
+
+    /// The time elapsed since the condition last transitioned.
+    fn age(&self) -> Duration;
+
+    /// The time elapsed between the last transition of the condition and `now`.
+    ///
+    /// The duration is negative if the last transition is after `now`.
+    fn age_at(&self, now: DateTime<Utc>) -> Duration;
+
+    /// Classifies how long before `now` the condition last transitioned.
+    fn age_bucket(&self, now: DateTime<Utc>) -> AgeBucket;
+
+    /// Whether the condition was observed against an older generation of the resource and has not
+    /// transitioned for longer than `max_age`.
+    ///
+    /// A condition without an observed generation is always considered to be from an older generation.
+    fn is_stale(&self, resource: &impl Resource, max_age: Duration) -> bool;
+
+    /// Whether the condition has the given status and has not transitioned for at least `min` as
+    /// of `now`.
+    ///
+    /// This relies on `last_transition_time` only moving on real transitions, so it measures how
+    /// long the condition has been stable. It cannot detect a condition that flapped and settled
+    /// back within a single reconcile, since no transition would have been recorded.
+    fn has_been_in_status_for(
+        &self,
+        status: ConditionStatus,
+        min: Duration,
+        now: DateTime<Utc>,
+    ) -> bool;
+
+    /// Whether the condition transitioned strictly after `since`.
+    ///
+    /// A condition that transitioned exactly at `since` has not transitioned since, so a watermark
+    /// taken from the last observed transition time does not match that same transition again.
+    fn transitioned_since(&self, since: DateTime<Utc>) -> bool;
+
+    /// Sets the status of the condition.
+    fn set_status(&mut self, status: ConditionStatus) -> bool;
+
+    /// Sets the status of the condition like [`set_status`](Self::set_status), stamping a
+    /// transition with `now` rather than the current time.
+    ///
+    /// The clock is not consulted, so callers can control transition times without overriding it
+    /// with [`clock::with_clock`].
+    fn set_status_at(&mut self, status: ConditionStatus, now: DateTime<Utc>) -> bool;
+
+    /// Sets the status of the condition to `True`.
+    fn set_true(&mut self) -> bool;
+
+    /// Sets the status of the condition to `False`.
+    fn set_false(&mut self) -> bool;
+
+    /// Sets the status of the condition to `Unknown`.
+    fn set_unknown(&mut self) -> bool;
+
+    /// Inverts the status of the condition, swapping `True` and `False`.
+    ///
+    /// An `Unknown` condition is left as is.
+    fn flip(&mut self) -> bool;
+
+    /// Resets the condition to `Unknown` with the given reason and message in a single transition,
+    /// typically to signal that a reconcile is in progress.
+    fn set_unknown_with(&mut self, reason: impl ToString, message: impl ToString) -> bool;
+
+    /// Resets the condition to the `Unknown` baseline of [`unknown`](Self::unknown), clearing its
+    /// reason, message, and observed generation in a single transition. The type is kept.
+    fn clear(&mut self) -> bool;
+
+    /// Sets the reason of the condition.
+    fn set_reason(&mut self, reason: impl ToString) -> bool;
+
+    /// Sets the reason of the condition to a typed reason.
+    fn set_reason_typed<R: ConditionReason>(&mut self, reason: R) -> bool;
+
+    /// Sets the status and typed reason of the condition in a single transition, leaving the
+    /// message untouched.
+    fn transition<R: ConditionReason>(&mut self, status: ConditionStatus, reason: R) -> bool;
+
+    /// Sets the message of the condition.
+    fn set_message(&mut self, message: impl ToString) -> bool;
+
+    /// Sets the message of the condition from format arguments, usually through the
+    /// [`set_message!`](crate::set_message) macro.
+    fn set_message_fmt(&mut self, args: fmt::Arguments<'_>) -> bool;
+
+    /// Sets the reason and message of the condition in a single transition.
+    fn set_reason_and_message(&mut self, reason: impl ToString, message: impl ToString) -> bool;
+
+    /// Sets the status, reason, and message of the condition in a single transition.
+    fn set_all(
+        &mut self,
+        status: ConditionStatus,
+        reason: impl ToString,
+        message: impl ToString,
+    ) -> bool;
+
+    /// Sets the status of the condition, unless it last transitioned less than `min_interval` ago
+    /// according to the [clock](crate::clock).
+    ///
+    /// A suppressed change is dropped rather than queued, so a noisy signal only converges once it
+    /// is set again after the interval has passed, typically on a later reconcile. Returns whether
+    /// the condition changed.
+    fn set_status_debounced(&mut self, status: ConditionStatus, min_interval: Duration) -> bool;
+
+    /// Sets the status, reason, and message of the condition in a single transition, unless it last
+    /// transitioned less than `min_interval` ago, like
+    /// [`set_status_debounced`](Self::set_status_debounced).
+    fn set_all_debounced(
+        &mut self,
+        status: ConditionStatus,
+        reason: impl ToString,
+        message: impl ToString,
+        min_interval: Duration,
+    ) -> bool;
+
+    /// Merges a condition of the same type from another signal, keeping whichever wins under
+    /// `precedence`. Returns whether the condition changed.
+    ///
+    /// With [`MostRecent`](MergePrecedence::MostRecent), `other` wins only if it transitioned
+    /// strictly later, and its transition time is kept. With
+    /// [`WorstStatus`](MergePrecedence::WorstStatus), `other` wins only if its status is strictly
+    /// worse, and the condition transitions at the current time like with the other setters.
+    /// Either way, the status, reason, message, and observed generation are taken from the winner.
+    fn merge(&mut self, other: &Condition, precedence: MergePrecedence) -> bool;
+
+    /// Sets the status of the condition and returns it, for building conditions fluently.
+    ///
+    /// Like [`set_status`](Self::set_status), this only bumps `last_transition_time` if the status
+    /// changed.
+    fn with_status(mut self, status: ConditionStatus) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_status(status);
+        self
+    }
+
+    /// Sets the reason of the condition and returns it, for building conditions fluently.
+    fn with_reason(mut self, reason: impl ToString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_reason(reason);
+        self
+    }
+
+    /// Sets the message of the condition and returns it, for building conditions fluently.
+    fn with_message(mut self, message: impl ToString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_message(message);
+        self
+    }
+
+    /// Sets `last_transition_time` to `now`, even if nothing else changed.
+    ///
+    /// This deliberately bypasses the rule that the transition time only moves when a field
+    /// changes, for heartbeat-style conditions that record when they were last confirmed. Prefer
+    /// the other setters for conditions that report state.
+    fn touch(&mut self, now: DateTime<Utc>);
+
+    /// Sets the observed generation of the condition to the current generation of the resource.
+    fn set_generation_from(&mut self, resource: &impl Resource) -> bool;
+
+    /// Sets the status, reason, and observed generation of the condition in a single transition,
+    /// recording that the current generation of the resource has been processed.
+    fn mark_observed(
+        &mut self,
+        resource: &impl Resource,
+        status: ConditionStatus,
+        reason: impl ToString,
+    ) -> bool;
+}
+
+impl ConditionExt for Condition {
+    fn builder() -> ConditionBuilder {
+        ConditionBuilder::new()
+    }
+
+    fn unknown(type_: impl ToString) -> Condition {
+        Condition {
+            type_: type_.to_string(),
+            status: ConditionStatus::Unknown.to_string(),
+            reason: String::new(),
+            message: String::new(),
+            observed_generation: None,
+            last_transition_time: Time(clock::now()),
+        }
+    }
+
+    #[inline]
+    fn status(&self) -> ConditionStatus {
+        let Ok(status) = self.status.parse();
+        status
+    }
+
+    #[inline]
+    fn is_status(&self, status: impl ToString) -> bool {
+        self.status == status.to_string()
+    }
+
+    #[inline]
+    fn is_true(&self) -> bool {
+        self.is_status(ConditionStatus::True)
+    }
+
+    #[inline]
+    fn is_false(&self) -> bool {
+        self.is_status(ConditionStatus::False)
+    }
+
+    #[inline]
+    fn is_unknown(&self) -> bool {
+        self.status() == ConditionStatus::Unknown
+    }
+
+    #[inline]
+    fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    #[inline]
+    fn message(&self) -> &str {
+        &self.message
+    }
+
+    #[inline]
+    fn transitioned_at(&self) -> DateTime<Utc> {
+        self.last_transition_time.0
+    }
+
+    #[inline]
+    fn as_bool(&self) -> Option<bool> {
+        self.status().as_bool()
+    }
+
+    #[inline]
+    fn is_healthier_than(&self, other: &Condition) -> bool {
+        self.status() > other.status()
+    }
+
+    fn display(&self) -> DisplayCondition<'_> {
+        DisplayCondition(self)
+    }
+
+    fn reason_or(&self, default: &str) -> String {
+        match self.reason() {
+            "" => default.to_string(),
+            reason => reason.to_string(),
+        }
+    }
+
+    fn message_or(&self, default: &str) -> String {
+        match self.message() {
+            "" => default.to_string(),
+            message => message.to_string(),
+        }
+    }
+
+    #[inline]
+    fn has_reason(&self, reason: impl ToString) -> bool {
+        self.reason == reason.to_string()
+    }
+
+    #[inline]
+    fn reason_is<R: ConditionReason + FromStr>(&self) -> Option<R> {
+        self.reason.parse().ok()
+    }
+
+    #[inline]
+    fn matches(&self, status: ConditionStatus, reason: impl ToString) -> bool {
+        self.status() == status && self.has_reason(reason)
+    }
+
+    #[inline]
+    fn matches_all(
+        &self,
+        status: Option<ConditionStatus>,
+        reason: Option<String>,
+        message: Option<String>,
+    ) -> bool {
+        status.is_none_or(|status| self.status() == status)
+            && reason.is_none_or(|reason| self.reason == reason)
+            && message.is_none_or(|message| self.message == message)
+    }
+
+    #[inline]
+    fn message_contains(&self, needle: &str) -> bool {
+        self.message.contains(needle)
+    }
+
+    #[cfg(feature = "regex")]
+    #[inline]
+    fn message_matches(&self, regex: &regex::Regex) -> bool {
+        regex.is_match(&self.message)
+    }
+
+    fn validate(&self) -> Result<(), ConditionValidationError> {
+        if self.type_.is_empty() {
+            return Err(ConditionValidationError::EmptyType);
+        }
+        if self.status().as_str() != self.status {
+            return Err(ConditionValidationError::NonCanonicalStatus(
+                self.status.clone(),
+            ));
+        }
+        if !self.is_unknown() && self.reason.is_empty() {
+            return Err(ConditionValidationError::MissingReason);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn semantically_eq(&self, other: &Condition) -> bool {
+        !has_changed(self, other)
+    }
+
+    fn needs_update(
+        &self,
+        status: ConditionStatus,
+        reason: &str,
+        message: &str,
+        observed_generation: Option<i64>,
+    ) -> bool {
+        let desired = Condition {
+            status: status.to_string(),
+            reason: reason.to_string(),
+            message: message.to_string(),
+            observed_generation: observed_generation.or(self.observed_generation),
+            ..self.clone()
+        };
+        has_changed(self, &desired)
+    }
+
+    #[inline]
+    fn is_current(&self, resource: &impl Resource) -> bool {
+        self.observed_generation == resource.meta().generation
+    }
+
+    #[inline]
+    fn age(&self) -> Duration {
+        self.age_at(clock::now())
+    }
+
+    #[inline]
+    fn age_at(&self, now: DateTime<Utc>) -> Duration {
+        now - self.last_transition_time.0
+    }
+
+    #[inline]
+    fn age_bucket(&self, now: DateTime<Utc>) -> AgeBucket {
+        AgeBucket::from_age(self.age_at(now))
+    }
+
+    #[inline]
+    fn is_stale(&self, resource: &impl Resource, max_age: Duration) -> bool {
+        let outdated = self.observed_generation.is_none() || !self.is_current(resource);
+        outdated && self.age() > max_age
+    }
+
+    #[inline]
+    fn has_been_in_status_for(
+        &self,
+        status: ConditionStatus,
+        min: Duration,
+        now: DateTime<Utc>,
+    ) -> bool {
+        self.status() == status && self.age_at(now) >= min
+    }
+
+    #[inline]
+    fn transitioned_since(&self, since: DateTime<Utc>) -> bool {
+        self.last_transition_time.0 > since
+    }
+
+    fn set_status(&mut self, status: ConditionStatus) -> bool {
+        update_condition(self, |condition| condition.status = status.to_string())
+    }
+
+    fn set_status_at(&mut self, status: ConditionStatus, now: DateTime<Utc>) -> bool {
+        update_condition_at(self, now, |condition| condition.status = status.to_string())
+    }
+
+    fn set_true(&mut self) -> bool {
+        self.set_status(ConditionStatus::True)
+    }
+
+    fn set_false(&mut self) -> bool {
+        self.set_status(ConditionStatus::False)
+    }
+
+    fn set_unknown(&mut self) -> bool {
+        self.set_status(ConditionStatus::Unknown)
+    }
+
+    fn set_unknown_with(&mut self, reason: impl ToString, message: impl ToString) -> bool {
+        self.set_all(ConditionStatus::Unknown, reason, message)
+    }
+
+    fn clear(&mut self) -> bool {
+        update_condition(self, |condition| {
+            condition.status = ConditionStatus::Unknown.to_string();
+            condition.reason.clear();
+            condition.message.clear();
+            condition.observed_generation = None;
+        })
+    }
+
+    fn flip(&mut self) -> bool {
+        let status = self.status().invert();
+        self.set_status(status)
+    }
+
+    fn set_reason(&mut self, reason: impl ToString) -> bool {
+        update_condition(self, |condition| condition.reason = reason.to_string())
+    }
+
+    fn set_reason_typed<R: ConditionReason>(&mut self, reason: R) -> bool {
+        self.set_reason(reason.as_str())
+    }
+
+    fn transition<R: ConditionReason>(&mut self, status: ConditionStatus, reason: R) -> bool {
+        update_condition(self, |condition| {
+            condition.status = status.to_string();
+            condition.reason = reason.as_str().to_string();
+        })
+    }
+
+    fn set_message(&mut self, message: impl ToString) -> bool {
+        update_condition(self, |condition| condition.message = message.to_string())
+    }
+
+    fn set_message_fmt(&mut self, args: fmt::Arguments<'_>) -> bool {
+        update_condition(self, |condition| condition.message = fmt::format(args))
+    }
+
+    fn set_reason_and_message(&mut self, reason: impl ToString, message: impl ToString) -> bool {
+        update_condition(self, |condition| {
+            condition.reason = reason.to_string();
+            condition.message = message.to_string();
+        })
+    }
+
+    fn set_all(
+        &mut self,
+        status: ConditionStatus,
+        reason: impl ToString,
+        message: impl ToString,
+    ) -> bool {
+        update_condition(self, |condition| {
+            condition.status = status.to_string();
+            condition.reason = reason.to_string();
+            condition.message = message.to_string();
+        })
+    }
+
+    fn set_status_debounced(&mut self, status: ConditionStatus, min_interval: Duration) -> bool {
+        if self.age() < min_interval {
+            return false;
+        }
+        self.set_status(status)
+    }
+
+    fn set_all_debounced(
+        &mut self,
+        status: ConditionStatus,
+        reason: impl ToString,
+        message: impl ToString,
+        min_interval: Duration,
+    ) -> bool {
+        if self.age() < min_interval {
+            return false;
+        }
+        self.set_all(status, reason, message)
+    }
+
+    fn merge(&mut self, other: &Condition, precedence: MergePrecedence) -> bool {
+        let adopt = |condition: &mut Condition| {
+            condition.status = other.status.clone();
+            condition.reason = other.reason.clone();
+            condition.message = other.message.clone();
+            condition.observed_generation = other.observed_generation;
+        };
+        match precedence {
+            MergePrecedence::MostRecent => {
+                if other.last_transition_time <= self.last_transition_time
+                    || self.semantically_eq(other)
+                {
+                    return false;
+                }
+                adopt(self);
+                self.last_transition_time = other.last_transition_time.clone();
+                true
+            }
+            MergePrecedence::WorstStatus => {
+                if other.status() >= self.status() {
+                    return false;
+                }
+                update_condition(self, adopt)
+            }
+        }
+    }
+
+    fn touch(&mut self, now: DateTime<Utc>) {
+        self.last_transition_time = Time(now);
+    }
+
+    fn set_generation_from(&mut self, resource: &impl Resource) -> bool {
+        update_condition(self, |condition| {
+            condition.observed_generation = resource.meta().generation
+        })
+    }
+
+    fn mark_observed(
+        &mut self,
+        resource: &impl Resource,
+        status: ConditionStatus,
+        reason: impl ToString,
+    ) -> bool {
+        update_condition(self, |condition| {
+            condition.status = status.to_string();
+            condition.reason = reason.to_string();
+            condition.observed_generation = resource.meta().generation;
+        })
+    }
+}
+
+/// Sets the message of a condition from a format string, returning whether it changed.
+///
+/// This is shorthand for [`ConditionExt::set_message_fmt`] with [`format_args!`], so the message
+/// is formatted inline:
+///
+/// ```
+/// use k8s_openapi::api::core::v1::Pod;
+/// use kube_observe::{HasStatusConditions, set_message};
+///
+/// let mut pod = Pod::default();
+/// let attempt = 3;
+/// assert!(set_message!(pod.condition_mut("Ready"), "retry {attempt}"));
+/// assert_eq!(pod.condition("Ready").message, "retry 3");
+/// ```
+#[macro_export]
+macro_rules! set_message {
+    ($condition:expr, $($arg:tt)+) => {{
+        use $crate::ConditionExt as _;
+        $condition.set_message_fmt(::std::format_args!($($arg)+))
+    }};
+}
+
+/// Applies `update` to the condition, bumping `last_transition_time` only if a field actually changed.
+///
+/// With the `tracing` feature enabled, every transition is also logged as an event.
+///
+/// Returns whether the condition changed.
+fn update_condition(condition: &mut Condition, update: impl FnOnce(&mut Condition)) -> bool {
+    update_condition_at(condition, clock::now(), update)
+}
+
+/// Applies `update` to the condition like [`update_condition`], stamping a transition with `now`.
+fn update_condition_at(
+    condition: &mut Condition,
+    now: DateTime<Utc>,
+    update: impl FnOnce(&mut Condition),
+) -> bool {
+    let mut updated = condition.clone();
+    update(&mut updated);
+
+    let changed = has_changed(condition, &updated);
+    if changed {
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            r#type = %updated.type_,
+            old_status = %condition.status,
+            new_status = %updated.status,
+            reason = %updated.reason,
+            "condition transitioned",
+        );
+        updated.last_transition_time = Time(now);
+        *condition = updated;
+    }
+    changed
+}
+
+/// Whether a transition from `previous` to `current` changes any field other than
+/// `last_transition_time`.
+pub(crate) fn has_changed(previous: &Condition, current: &Condition) -> bool {
+    previous.type_ != current.type_
+        || previous.status != current.status
+        || previous.reason != current.reason
+        || previous.message != current.message
+        || previous.observed_generation != current.observed_generation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::{DummyStatus, ReadyReasons, dummy, epoch};
+    use super::*;
+    use crate::clock::FixedClock;
+
+    #[test]
+    fn set_status_at_stamps_given_time() {
+        let now = DateTime::UNIX_EPOCH + Duration::hours(1);
+        let mut condition = Condition {
+            last_transition_time: epoch(),
+            ..Condition::unknown("Ready")
+        };
+
+        assert!(condition.set_status_at(ConditionStatus::True, now));
+        assert_eq!(condition.last_transition_time, Time(now));
+
+        let later = now + Duration::hours(1);
+        assert!(!condition.set_status_at(ConditionStatus::True, later));
+        assert_eq!(condition.last_transition_time, Time(now));
+    }
+
+    #[test]
+    fn unknown_condition_uses_clock() {
+        let now = DateTime::UNIX_EPOCH + Duration::hours(1);
+        let condition = clock::with_clock(FixedClock(now), || Condition::unknown("Ready"));
+        assert_eq!(condition.type_, "Ready");
+        assert!(condition.is_unknown());
+        assert_eq!(condition.last_transition_time, Time(now));
+    }
+
+    #[test]
+    fn missing_condition_is_unknown() {
+        let dummy = dummy();
+        let condition = dummy.condition("Ready");
+        assert_eq!(condition.type_, "Ready");
+        assert!(condition.is_unknown());
+        assert!(condition.has_reason(""));
+    }
+
+    #[test]
+    fn condition_opt_distinguishes_missing_from_unknown() {
+        let mut dummy = dummy();
+        assert_eq!(dummy.condition_opt("Ready"), None);
+
+        dummy.condition_mut("Ready");
+        let ready = dummy.condition_opt("Ready").unwrap();
+        assert!(ready.is_unknown());
+        assert!(dummy.condition("Ready").semantically_eq(&ready));
+    }
+
+    #[test]
+    fn presence_reflects_stored_conditions() {
+        let mut dummy = dummy();
+        assert!(!dummy.has_condition("Ready"));
+        assert!(dummy.condition_types().is_empty());
+
+        dummy.condition_mut("Ready").set_true();
+        dummy.condition_mut("Available").set_unknown();
+        assert!(dummy.has_condition("Ready"));
+        assert!(dummy.has_condition("Available"));
+        assert!(!dummy.has_condition("Degraded"));
+        assert_eq!(dummy.condition_types(), ["Available", "Ready"]);
+    }
+
+    #[test]
+    fn checks_several_conditions_at_once() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+        dummy.condition_mut("Available").set_true();
+        assert!(dummy.all_true(&["Ready", "Available"]));
+        assert!(!dummy.all_true(&["Ready", "Available", "Synced"]));
+        assert!(!dummy.any_false(&["Ready", "Synced"]));
+        assert!(dummy.all_true(&[]));
+
+        dummy.condition_mut("Degraded").set_false();
+        assert!(!dummy.all_true(&["Ready", "Degraded"]));
+        assert!(dummy.any_false(&["Ready", "Degraded", "Synced"]));
+    }
+
+    #[test]
+    fn condition_mut_inserts_and_persists() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+        dummy.condition_mut("Ready").set_reason(ReadyReasons::Yoyo);
+
+        let condition = dummy.condition("Ready");
+        assert!(condition.is_true());
+        assert!(condition.has_reason(ReadyReasons::Yoyo));
+        assert_eq!(dummy.status.unwrap().conditions.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn condition_mut_keeps_existing_reason() {
+        let mut dummy = dummy();
+        dummy.status = Some(DummyStatus {
+            conditions: Some(vec![Condition {
+                reason: ReadyReasons::Yoyo.to_string(),
+                ..Condition::unknown("Ready")
+            }]),
+        });
+
+        assert!(dummy.condition_mut("Ready").has_reason(ReadyReasons::Yoyo));
+        dummy.condition_mut("Available");
+        assert!(dummy.condition("Ready").has_reason(ReadyReasons::Yoyo));
+        assert_eq!(dummy.condition_types(), ["Available", "Ready"]);
+    }
+
+    #[test]
+    fn setters_report_transitions() {
+        let mut dummy = dummy();
+        let mut condition = dummy.condition_mut("Ready");
+        condition.last_transition_time = epoch();
+
+        assert!(condition.set_true());
+        assert_ne!(condition.last_transition_time, epoch());
+
+        condition.last_transition_time = epoch();
+        assert!(!condition.set_true());
+        assert!(condition.set_reason(ReadyReasons::Yoyo));
+        condition.last_transition_time = epoch();
+        assert!(!condition.set_reason(ReadyReasons::Yoyo));
+        assert!(condition.set_message("all good"));
+        condition.last_transition_time = epoch();
+        assert!(!condition.set_message("all good"));
+        assert_eq!(condition.last_transition_time, epoch());
+
+        assert!(condition.set_false());
+        assert!(condition.set_unknown());
+    }
+
+    #[test]
+    fn round_trips_typed_reason() {
+        let mut dummy = dummy();
+        let mut condition = dummy.condition_mut("Ready");
+        assert_eq!(condition.reason_is::<ReadyReasons>(), None);
+
+        assert!(condition.set_reason_typed(ReadyReasons::Yoyo));
+        assert!(!condition.set_reason_typed(ReadyReasons::Yoyo));
+        assert_eq!(condition.reason_is(), Some(ReadyReasons::Yoyo));
+    }
+
+    #[test]
+    fn reads_status_as_bool() {
+        let condition = Condition::unknown("Ready");
+        assert_eq!(condition.as_bool(), None);
+        assert_eq!(
+            condition
+                .clone()
+                .with_status(ConditionStatus::True)
+                .as_bool(),
+            Some(true)
+        );
+        assert_eq!(
+            condition.with_status(ConditionStatus::False).as_bool(),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn compares_health() {
+        let true_ = Condition::unknown("Ready").with_status(ConditionStatus::True);
+        let unknown = Condition::unknown("Ready");
+        let false_ = Condition::unknown("Ready").with_status(ConditionStatus::False);
+
+        assert!(true_.is_healthier_than(&unknown));
+        assert!(unknown.is_healthier_than(&false_));
+        assert!(true_.is_healthier_than(&false_));
+        assert!(!false_.is_healthier_than(&unknown));
+        assert!(!unknown.is_healthier_than(&unknown));
+    }
+
+    #[test]
+    fn falls_back_to_default_reason_and_message() {
+        let condition = Condition::unknown("Ready");
+        assert_eq!(condition.reason_or("Pending"), "Pending");
+        assert_eq!(condition.message_or("-"), "-");
+
+        let condition = condition
+            .with_reason(ReadyReasons::Yoyo)
+            .with_message("all good");
+        assert_eq!(condition.reason_or("Pending"), "Yoyo");
+        assert_eq!(condition.message_or("-"), "all good");
+    }
+
+    #[test]
+    fn transitions_status_and_typed_reason_once() {
+        let now = DateTime::UNIX_EPOCH + Duration::days(1);
+        clock::with_clock(FixedClock(now), || {
+            let mut condition = Condition {
+                last_transition_time: epoch(),
+                ..Condition::unknown("Ready")
+            };
+
+            assert!(condition.transition(ConditionStatus::False, ReadyReasons::Yoyo));
+            assert!(condition.matches(ConditionStatus::False, ReadyReasons::Yoyo));
+            assert_eq!(condition.last_transition_time, Time(now));
+
+            condition.last_transition_time = epoch();
+            assert!(!condition.transition(ConditionStatus::False, ReadyReasons::Yoyo));
+            assert_eq!(condition.last_transition_time, epoch());
+        });
+    }
+
+    #[test]
+    fn mark_observed_makes_condition_current() {
+        let resource = dummy();
+        let mut condition = Condition::unknown("Ready");
+        assert!(!condition.is_current(&resource));
+
+        assert!(condition.mark_observed(&resource, ConditionStatus::True, ReadyReasons::Yoyo));
+        assert!(condition.is_current(&resource));
+        assert!(condition.matches(ConditionStatus::True, ReadyReasons::Yoyo));
+
+        condition.last_transition_time = epoch();
+        assert!(!condition.mark_observed(&resource, ConditionStatus::True, ReadyReasons::Yoyo));
+        assert_eq!(condition.last_transition_time, epoch());
+    }
+
+    #[test]
+    fn stable_only_after_minimum_duration() {
+        let mut condition = Condition {
+            status: ConditionStatus::True.to_string(),
+            last_transition_time: epoch(),
+            ..Condition::unknown("Ready")
+        };
+        let min = Duration::seconds(30);
+        let now = DateTime::UNIX_EPOCH + min;
+
+        assert!(condition.has_been_in_status_for(ConditionStatus::True, min, now));
+        assert!(!condition.has_been_in_status_for(ConditionStatus::False, min, now));
+        assert!(!condition.has_been_in_status_for(
+            ConditionStatus::True,
+            min,
+            now - Duration::seconds(1)
+        ));
+
+        condition.set_false();
+        condition.set_true();
+        assert!(!condition.has_been_in_status_for(ConditionStatus::True, min, clock::now()));
+    }
+
+    #[test]
+    fn semantic_equality_ignores_transition_time() {
+        let condition = Condition::unknown("Ready");
+        let later = Condition {
+            last_transition_time: epoch(),
+            ..condition.clone()
+        };
+        assert_ne!(condition, later);
+        assert!(condition.semantically_eq(&later));
+
+        let observed = Condition {
+            observed_generation: Some(3),
+            ..later
+        };
+        assert!(!condition.semantically_eq(&observed));
+    }
+
+    #[test]
+    fn flip_inverts_known_statuses() {
+        let mut condition = Condition {
+            last_transition_time: epoch(),
+            ..Condition::unknown("Ready")
+        };
+        assert!(!condition.flip());
+        assert!(condition.is_unknown());
+        assert_eq!(condition.last_transition_time, epoch());
+
+        condition.set_true();
+        assert!(condition.flip());
+        assert!(condition.is_false());
+
+        condition.last_transition_time = epoch();
+        assert!(condition.flip());
+        assert!(condition.is_true());
+        assert_ne!(condition.last_transition_time, epoch());
+    }
+
+    #[test]
+    fn sets_reason_and_message_together() {
+        let mut condition = Condition::unknown("Ready");
+        assert_eq!(condition.reason(), "");
+        assert!(condition.set_reason_and_message(ReadyReasons::Yoyo, "first"));
+        assert_eq!(condition.reason(), "Yoyo");
+        assert_eq!(condition.message(), "first");
+
+        condition.last_transition_time = epoch();
+        assert!(condition.set_reason_and_message(ReadyReasons::Yoyo, "second"));
+        assert_ne!(condition.last_transition_time, epoch());
+        assert!(condition.has_reason(ReadyReasons::Yoyo));
+        assert_eq!(condition.message, "second");
+
+        condition.last_transition_time = epoch();
+        assert!(!condition.set_reason_and_message(ReadyReasons::Yoyo, "second"));
+        assert_eq!(condition.last_transition_time, epoch());
+    }
+
+    #[test]
+    fn clears_to_unknown_baseline() {
+        let now = DateTime::UNIX_EPOCH + Duration::days(1);
+        clock::with_clock(FixedClock(now), || {
+            let mut condition = Condition {
+                status: ConditionStatus::True.to_string(),
+                reason: "Reconciled".to_string(),
+                message: "all good".to_string(),
+                observed_generation: Some(3),
+                last_transition_time: epoch(),
+                type_: "Ready".to_string(),
+            };
+
+            assert!(condition.clear());
+            assert_eq!(condition, Condition::unknown("Ready"));
+
+            condition.last_transition_time = epoch();
+            assert!(!condition.clear());
+            assert_eq!(condition.last_transition_time, epoch());
+        });
+    }
+
+    #[test]
+    fn resets_to_unknown_with_reason() {
+        let now = DateTime::UNIX_EPOCH + Duration::days(1);
+        clock::with_clock(FixedClock(now), || {
+            let mut condition = Condition {
+                status: ConditionStatus::True.to_string(),
+                last_transition_time: epoch(),
+                ..Condition::unknown("Ready")
+            };
+
+            assert!(condition.set_unknown_with("Reconciling", "checking replicas"));
+            assert!(condition.matches(ConditionStatus::Unknown, "Reconciling"));
+            assert_eq!(condition.message, "checking replicas");
+            assert_eq!(condition.last_transition_time, Time(now));
+
+            condition.last_transition_time = epoch();
+            assert!(!condition.set_unknown_with("Reconciling", "checking replicas"));
+            assert_eq!(condition.last_transition_time, epoch());
+        });
+    }
+
+    #[test]
+    fn touch_always_moves_time() {
+        let mut condition = Condition {
+            last_transition_time: epoch(),
+            ..Condition::unknown("Heartbeat")
+        };
+        let now = DateTime::UNIX_EPOCH + Duration::minutes(5);
+
+        condition.touch(now);
+        assert_eq!(condition.last_transition_time, Time(now));
+        assert!(condition.is_unknown());
+
+        condition.touch(now + Duration::minutes(5));
+        assert_eq!(
+            condition.age_at(now + Duration::minutes(5)),
+            Duration::zero()
+        );
+    }
+
+    #[test]
+    fn builds_condition_fluently() {
+        let now = DateTime::UNIX_EPOCH + Duration::days(1);
+        let condition = clock::with_clock(FixedClock(now), || {
+            Condition::unknown("Ready")
+                .with_status(ConditionStatus::True)
+                .with_reason(ReadyReasons::Yoyo)
+                .with_message("all good")
+        });
+
+        assert!(condition.matches(ConditionStatus::True, ReadyReasons::Yoyo));
+        assert_eq!(condition.message, "all good");
+        assert_eq!(condition.last_transition_time, Time(now));
+
+        let unchanged = Condition {
+            last_transition_time: epoch(),
+            ..condition
+        }
+        .with_status(ConditionStatus::True);
+        assert_eq!(unchanged.last_transition_time, epoch());
+    }
+
+    #[test]
+    fn validates_conventions() {
+        let ready = Condition::unknown("Ready");
+        assert_eq!(ready.validate(), Ok(()));
+        assert_eq!(
+            ready
+                .clone()
+                .with_reason(ReadyReasons::Yoyo)
+                .with_status(ConditionStatus::True)
+                .validate(),
+            Ok(())
+        );
+
+        assert_eq!(
+            Condition::unknown("").validate(),
+            Err(ConditionValidationError::EmptyType)
+        );
+        assert_eq!(
+            Condition {
+                status: "true".to_string(),
+                ..ready.clone()
+            }
+            .validate(),
+            Err(ConditionValidationError::NonCanonicalStatus(
+                "true".to_string()
+            ))
+        );
+        assert_eq!(
+            ready.with_status(ConditionStatus::False).validate(),
+            Err(ConditionValidationError::MissingReason)
+        );
+    }
+
+    #[test]
+    fn formats_message_inline() {
+        let mut dummy = dummy();
+        let mut condition = dummy.condition_mut("Ready");
+
+        for attempt in 1..=2 {
+            assert!(crate::set_message!(condition, "retry {}", attempt));
+            assert_eq!(condition.message, format!("retry {attempt}"));
+
+            condition.last_transition_time = epoch();
+            assert!(!crate::set_message!(condition, "retry {}", attempt));
+            assert_eq!(condition.last_transition_time, epoch());
+        }
+    }
+
+    #[test]
+    fn no_op_does_not_move_time() {
+        let now = DateTime::UNIX_EPOCH + Duration::days(1);
+        clock::with_clock(FixedClock(now), || {
+            let mut dummy = dummy();
+            let mut condition = dummy.condition_mut("Ready");
+            condition.last_transition_time = epoch();
+
+            assert!(condition.set_true());
+            assert_eq!(condition.last_transition_time, Time(now));
+
+            condition.last_transition_time = epoch();
+            assert!(!condition.set_true());
+            assert_eq!(condition.last_transition_time, epoch());
+        });
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn transitions_are_traced() {
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        #[derive(Clone, Default)]
+        struct Recorder(Arc<Mutex<Vec<Vec<String>>>>);
+
+        struct Fields<'a>(&'a mut Vec<String>);
+
+        impl Visit for Fields<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0.push(format!("{}={value:?}", field.name()));
+            }
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                let mut fields = Vec::new();
+                event.record(&mut Fields(&mut fields));
+                self.0.lock().unwrap().push(fields);
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut dummy = dummy();
+            dummy
+                .condition_mut("Ready")
+                .set_all(ConditionStatus::True, ReadyReasons::Yoyo, "");
+            dummy.condition_mut("Ready").set_true();
+        });
+
+        let events = recorder.0.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0],
+            [
+                "message=condition transitioned",
+                "type=Ready",
+                "old_status=Unknown",
+                "new_status=True",
+                "reason=Yoyo",
+            ]
+        );
+    }
+
+    #[test]
+    fn changes_accumulate_across_conditions() {
+        let mut dummy = dummy();
+        let mut changed = false;
+        changed |= dummy.condition_mut("Ready").set_true();
+        changed |= dummy.condition_mut("Available").set_false();
+        assert!(changed);
+
+        let mut changed = false;
+        changed |= dummy.condition_mut("Ready").set_true();
+        changed |= dummy.condition_mut("Available").set_false();
+        assert!(!changed);
+    }
+
+    #[test]
+    fn conditions_sorted_orders_by_type() {
+        let mut dummy = dummy();
+        dummy.status = Some(DummyStatus {
+            conditions: Some(vec![
+                Condition::unknown("Ready"),
+                Condition::unknown("Available"),
+            ]),
+        });
+
+        let types: Vec<_> = dummy
+            .conditions_sorted()
+            .into_iter()
+            .map(|condition| condition.type_)
+            .collect();
+        assert_eq!(types, ["Available", "Ready"]);
+    }
+
+    #[test]
+    fn prunes_conditions_from_older_generations() {
+        let mut dummy = dummy();
+        dummy.status = Some(DummyStatus {
+            conditions: Some(vec![
+                Condition {
+                    observed_generation: Some(3),
+                    ..Condition::unknown("Available")
+                },
+                Condition {
+                    observed_generation: Some(2),
+                    ..Condition::unknown("Legacy")
+                },
+                Condition::unknown("Ready"),
+            ]),
+        });
+
+        let resource = dummy.clone();
+        let pruned = dummy.prune_stale(&resource);
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].type_, "Legacy");
+        assert_eq!(dummy.condition_types(), ["Available", "Ready"]);
+    }
+
+    #[test]
+    fn reports_conditions_lagging_generation() {
+        let mut dummy = dummy();
+        dummy.status = Some(DummyStatus {
+            conditions: Some(vec![
+                Condition {
+                    observed_generation: Some(3),
+                    ..Condition::unknown("Available")
+                },
+                Condition {
+                    observed_generation: Some(2),
+                    ..Condition::unknown("Legacy")
+                },
+                Condition::unknown("Ready"),
+                Condition {
+                    observed_generation: Some(4),
+                    ..Condition::unknown("Synced")
+                },
+            ]),
+        });
+
+        assert_eq!(
+            dummy.stale_conditions(&dummy),
+            ["Legacy", "Ready", "Synced"]
+        );
+    }
+
+    #[test]
+    fn condition_or_else_builds_default_only_when_absent() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+
+        let not_observed = |type_| Condition::unknown(type_).with_reason("NotObservedYet");
+        let synced = dummy.condition_or_else("Synced", not_observed);
+        assert_eq!(synced.type_, "Synced");
+        assert!(synced.is_unknown());
+        assert!(synced.has_reason("NotObservedYet"));
+
+        let ready = dummy.condition_or_else("Ready", |_| panic!("Ready is present"));
+        assert!(ready.is_true());
+    }
+
+    #[test]
+    fn finds_worst_condition() {
+        let mut dummy = dummy();
+        dummy.status = Some(DummyStatus {
+            conditions: Some(vec![
+                Condition {
+                    last_transition_time: epoch(),
+                    ..Condition::unknown("Available").with_status(ConditionStatus::False)
+                },
+                Condition::unknown("Progressing").with_status(ConditionStatus::True),
+                Condition::unknown("Ready").with_status(ConditionStatus::False),
+            ]),
+        });
+
+        let types = ["Available", "Progressing", "Ready", "Synced"];
+        assert_eq!(dummy.worst_condition(&types).type_, "Ready");
+        assert_eq!(
+            dummy.worst_condition(&["Progressing", "Synced"]).type_,
+            "Synced"
+        );
+        let empty = dummy.worst_condition(&[]);
+        assert_eq!(empty.type_, "");
+        assert!(empty.is_unknown());
+
+        let best = dummy.worst_condition_by(&types, |status| status == ConditionStatus::True);
+        assert_eq!(best.type_, "Progressing");
+    }
+
+    #[test]
+    fn computes_ready_from_dependencies() {
+        let mut dummy = dummy();
+        dummy.condition_mut("DBReady").set_true();
+        dummy.condition_mut("CacheReady").set_true();
+
+        let ready = dummy.compute_ready(&["DBReady", "CacheReady"]);
+        assert_eq!(ready.type_, "Ready");
+        assert!(ready.is_true());
+        assert!(ready.has_reason("DependenciesReady"));
+        assert_eq!(ready.message, "");
+
+        dummy.condition_mut("CacheReady").set_all(
+            ConditionStatus::False,
+            "Evicted",
+            "cache evicted",
+        );
+        let ready = dummy.compute_ready(&["DBReady", "CacheReady"]);
+        assert!(ready.is_false());
+        assert!(ready.has_reason("DependenciesNotReady"));
+        assert_eq!(ready.message, "CacheReady is False (Evicted)");
+
+        dummy.condition_mut("CacheReady").set_true();
+        let ready = dummy.compute_ready(&["DBReady", "CacheReady", "QueueReady"]);
+        assert!(ready.is_unknown());
+        assert!(ready.has_reason("DependenciesUnknown"));
+        assert_eq!(ready.message, "QueueReady is Unknown");
+
+        assert!(dummy.set_condition(dummy.compute_ready(&["DBReady"])));
+        assert!(dummy.condition("Ready").is_true());
+    }
+
+    #[test]
+    fn retains_only_kept_types() {
+        let mut dummy = dummy();
+        for type_ in ["Available", "Legacy", "Ready"] {
+            dummy.condition_mut(type_);
+        }
+
+        dummy.retain_condition_types(&["Ready"]);
+        assert_eq!(dummy.condition_types(), ["Ready"]);
+    }
+
+    #[test]
+    fn condition_mut_for_stamps_generation() {
+        let mut dummy = dummy();
+        dummy.condition_mut_for(&dummy.clone(), "Ready").set_true();
+        assert!(dummy.condition("Ready").is_current(&dummy));
+
+        dummy.metadata.generation = Some(4);
+        dummy.condition_mut_for(&dummy.clone(), "Ready").set_true();
+        assert!(!dummy.condition("Ready").is_current(&dummy));
+
+        dummy.condition_mut_for(&dummy.clone(), "Ready").set_false();
+        assert!(dummy.condition("Ready").is_current(&dummy));
+    }
+
+    #[test]
+    fn condition_mut_tracked_follows_own_generation() {
+        let mut dummy = dummy();
+        {
+            let mut ready = dummy.condition_mut_tracked("Ready");
+            assert_eq!(ready.generation(), Some(3));
+            assert!(ready.set_all(ConditionStatus::True, ReadyReasons::Yoyo, "ready"));
+        }
+        assert_eq!(dummy.condition("Ready").observed_generation, Some(3));
+        assert!(dummy.condition("Ready").is_current(&dummy));
+    }
+
+    #[test]
+    fn applies_conditions_in_bulk() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Available").set_true();
+        dummy
+            .condition_mut("Ready")
+            .set_all(ConditionStatus::False, "Waiting", "");
+
+        let conditions = BTreeMap::from([
+            (
+                "Available".to_string(),
+                (ConditionStatus::True, String::new(), String::new()),
+            ),
+            (
+                "Ready".to_string(),
+                (ConditionStatus::False, "Waiting".to_string(), String::new()),
+            ),
+            (
+                "Synced".to_string(),
+                (ConditionStatus::True, "UpToDate".to_string(), String::new()),
+            ),
+        ]);
+        let changed = dummy.apply_conditions(&conditions);
+        assert_eq!(changed, BTreeSet::from(["Synced".to_string()]));
+        assert!(
+            dummy
+                .condition("Synced")
+                .matches(ConditionStatus::True, "UpToDate")
+        );
+        assert!(dummy.apply_conditions(&conditions).is_empty());
+    }
+
+    #[test]
+    fn updates_every_condition_in_place() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+        dummy.condition_mut("Available").set_true();
+
+        let resource = dummy.clone();
+        for condition in dummy.conditions_mut().iter_mut() {
+            assert!(condition.set_generation_from(&resource));
+        }
+        assert!(
+            dummy
+                .conditions()
+                .iter()
+                .all(|condition| condition.is_current(&resource))
+        );
+    }
+
+    #[test]
+    fn ensure_condition_only_inserts_once() {
+        let mut dummy = dummy();
+        let pending = dummy.ensure_condition("Ready", "Pending").clone();
+        assert!(pending.matches(ConditionStatus::Unknown, "Pending"));
+
+        dummy.condition_mut("Ready").last_transition_time = epoch();
+        let ensured = dummy.ensure_condition("Ready", "Other").clone();
+        assert!(ensured.has_reason("Pending"));
+        assert_eq!(ensured.last_transition_time, epoch());
+        assert_eq!(dummy.condition_types(), ["Ready"]);
+    }
+
+    #[test]
+    fn seeds_missing_standard_conditions() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+
+        let types = [standard::READY, standard::AVAILABLE, standard::PROGRESSING];
+        assert!(dummy.initialize_standard_conditions(&types, "Pending"));
+        assert!(dummy.condition("Ready").is_true());
+        assert!(
+            dummy
+                .condition("Available")
+                .matches(ConditionStatus::Unknown, "Pending")
+        );
+        assert!(
+            dummy
+                .condition("Progressing")
+                .matches(ConditionStatus::Unknown, "Pending")
+        );
+        assert_eq!(dummy.condition_types().len(), 3);
+
+        assert!(!dummy.initialize_standard_conditions(&types, "Other"));
+        assert!(dummy.condition("Available").has_reason("Pending"));
+    }
+
+    #[test]
+    fn removes_condition() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+        dummy.condition_mut("Degraded").set_true();
+
+        let removed = dummy.remove_condition("Degraded").unwrap();
+        assert_eq!(removed.type_, "Degraded");
+        assert!(removed.is_true());
+        assert!(dummy.remove_condition("Degraded").is_none());
+        assert!(dummy.condition("Degraded").is_unknown());
+        assert!(dummy.condition("Ready").is_true());
+    }
+
+    #[test]
+    fn set_condition_if_absent_keeps_existing() {
+        let mut dummy = dummy();
+        let ready = Condition::unknown("Ready").with_status(ConditionStatus::True);
+        assert!(dummy.set_condition_if_absent(ready));
+        assert!(dummy.condition("Ready").is_true());
+
+        let ready = Condition::unknown("Ready").with_status(ConditionStatus::False);
+        assert!(!dummy.set_condition_if_absent(ready));
+        assert!(dummy.condition("Ready").is_true());
+        assert_eq!(dummy.condition_types(), ["Ready"]);
+    }
+
+    #[test]
+    fn set_condition_overwrites_existing() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_false();
+        dummy.condition_mut("Available").set_true();
+        dummy.condition_mut("Ready").last_transition_time = epoch();
+
+        let incoming = ConditionBuilder::new()
+            .type_("Ready")
+            .status(ConditionStatus::False)
+            .build();
+        assert!(!dummy.set_condition(incoming.clone()));
+        assert_eq!(dummy.condition("Ready").last_transition_time, epoch());
+
+        assert!(dummy.set_condition(Condition {
+            reason: ReadyReasons::Yoyo.to_string(),
+            ..incoming
+        }));
+        let ready = dummy.condition("Ready");
+        assert!(ready.is_false());
+        assert!(ready.has_reason(ReadyReasons::Yoyo));
+        assert_ne!(ready.last_transition_time, epoch());
+
+        let types: Vec<_> = dummy
+            .status
+            .unwrap()
+            .conditions
+            .unwrap()
+            .into_iter()
+            .map(|c| c.type_)
+            .collect();
+        assert_eq!(types, ["Available", "Ready"]);
+    }
+
+    #[test]
+    fn try_condition_mut_delegates() {
+        let mut dummy = dummy();
+        assert!(dummy.try_condition_mut("Ready").unwrap().set_true());
+        assert!(dummy.condition("Ready").is_true());
+    }
+
+    /// A resource whose conditions are owned by another component.
+    struct ReadOnly;
+
+    impl HasStatusConditions for ReadOnly {
+        const SUPPORTS_CONDITION_MUT: bool = false;
+
+        fn condition_opt(&self, _type_: impl ToString) -> Option<Condition> {
+            None
+        }
+
+        fn condition_types(&self) -> Vec<String> {
+            Vec::new()
+        }
+
+        fn condition_mut(&mut self, _type_: impl ToString) -> impl DerefMut<Target = Condition> {
+            unimplemented!() as &mut Condition
+        }
+
+        fn conditions_mut(&mut self) -> impl DerefMut<Target = Vec<Condition>> {
+            unimplemented!() as &mut Vec<Condition>
+        }
+
+        fn remove_condition(&mut self, _type_: impl ToString) -> Option<Condition> {
+            None
+        }
+    }
+
+    #[test]
+    fn try_condition_mut_rejects_read_only_resources() {
+        let mut read_only = ReadOnly;
+        assert_eq!(
+            read_only.try_condition_mut("Ready").err(),
+            Some(ConditionError::Unsupported)
+        );
+        assert_eq!(
+            read_only.try_conditions_mut().err(),
+            Some(ConditionError::Unsupported)
+        );
+    }
+
+    #[test]
+    fn matches_custom_status() {
+        let condition = Condition {
+            status: "Degraded".to_string(),
+            ..Condition::unknown("Health")
+        };
+        assert!(condition.is_status("Degraded"));
+        assert!(!condition.is_status("degraded"));
+        assert!(!condition.is_true());
+        assert!(condition.is_unknown());
+        assert!(Condition::unknown("Ready").is_status(ConditionStatus::Unknown));
+    }
+
+    #[test]
+    fn status_follows_set_status() {
+        let mut dummy = dummy();
+        let mut condition = dummy.condition_mut("Ready");
+        assert_eq!(condition.status(), ConditionStatus::Unknown);
+
+        assert!(condition.set_status(ConditionStatus::True));
+        assert_eq!(condition.status, "True");
+        assert!(condition.is_true());
+        assert!(!condition.set_status(ConditionStatus::True));
+
+        condition.status = "Bogus".to_string();
+        assert_eq!(condition.status(), ConditionStatus::Unknown);
+    }
+
+    #[test]
+    fn finds_substrings_of_message() {
+        let condition =
+            Condition::unknown("Ready").with_message("Back-off pulling image: ImagePullBackOff");
+        assert!(condition.message_contains("ImagePullBackOff"));
+        assert!(!condition.message_contains("imagepullbackoff"));
+        assert!(!condition.message_contains("CrashLoopBackOff"));
+        assert!(Condition::unknown("Ready").message_contains(""));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn matches_message_against_regex() {
+        let condition =
+            Condition::unknown("Ready").with_message("Back-off pulling image: ImagePullBackOff");
+        assert!(condition.message_matches(&regex::Regex::new(r"(Image|ErrImage)Pull").unwrap()));
+        assert!(!condition.message_matches(&regex::Regex::new(r"^ImagePull").unwrap()));
+    }
+
+    #[test]
+    fn matches_status_reason_and_message() {
+        let condition = ConditionBuilder::new()
+            .type_("Ready")
+            .status(ConditionStatus::True)
+            .reason(ReadyReasons::Yoyo)
+            .message("all good")
+            .build();
+
+        assert!(condition.matches(ConditionStatus::True, ReadyReasons::Yoyo));
+        assert!(!condition.matches(ConditionStatus::False, ReadyReasons::Yoyo));
+        assert!(!condition.matches(ConditionStatus::True, "Other"));
+
+        assert!(condition.matches_all(None, None, None));
+        assert!(condition.matches_all(Some(ConditionStatus::True), None, Some("all good".into())));
+        assert!(!condition.matches_all(None, None, Some("all".into())));
+        assert!(!condition.matches_all(None, Some("Other".into()), None));
+    }
+
+    #[test]
+    fn age_is_measured_from_last_transition() {
+        let condition = Condition {
+            last_transition_time: epoch(),
+            ..Condition::unknown("Ready")
+        };
+        let now = DateTime::UNIX_EPOCH + Duration::minutes(5);
+        assert_eq!(condition.transitioned_at(), DateTime::UNIX_EPOCH);
+        assert_eq!(condition.age_at(now), Duration::minutes(5));
+        assert_eq!(
+            condition.age_at(DateTime::UNIX_EPOCH - Duration::seconds(1)),
+            Duration::seconds(-1)
+        );
+        assert!(condition.age() > Duration::zero());
+    }
+
+    #[test]
+    fn transitioned_since_is_exclusive() {
+        let condition = Condition {
+            last_transition_time: epoch(),
+            ..Condition::unknown("Ready")
+        };
+        assert!(!condition.transitioned_since(DateTime::UNIX_EPOCH));
+        assert!(condition.transitioned_since(DateTime::UNIX_EPOCH - Duration::nanoseconds(1)));
+        assert!(!condition.transitioned_since(DateTime::UNIX_EPOCH + Duration::seconds(1)));
+    }
+
+    #[test]
+    fn stale_conditions_are_old_and_outdated() {
+        let mut dummy = dummy();
+        let resource = dummy.clone();
+        let mut condition = dummy.condition_mut("Ready");
+        condition.last_transition_time = epoch();
+        assert!(condition.is_stale(&resource, Duration::minutes(1)));
+
+        condition.observed_generation = Some(2);
+        condition.last_transition_time = epoch();
+        assert!(condition.is_stale(&resource, Duration::minutes(1)));
+
+        condition.last_transition_time = Time(Utc::now());
+        assert!(!condition.is_stale(&resource, Duration::minutes(1)));
+
+        condition.observed_generation = Some(3);
+        condition.last_transition_time = epoch();
+        assert!(!condition.is_stale(&resource, Duration::minutes(1)));
+    }
+
+    #[test]
+    fn needs_update_for_any_changed_field() {
+        let condition = Condition {
+            observed_generation: Some(3),
+            ..Condition::unknown("Ready")
+                .with_status(ConditionStatus::True)
+                .with_reason("Reconciled")
+                .with_message("all good")
+        };
+        let status = ConditionStatus::True;
+
+        assert!(!condition.needs_update(status, "Reconciled", "all good", None));
+        assert!(!condition.needs_update(status, "Reconciled", "all good", Some(3)));
+        assert!(condition.needs_update(ConditionStatus::False, "Reconciled", "all good", None));
+        assert!(condition.needs_update(status, "Other", "all good", None));
+        assert!(condition.needs_update(status, "Reconciled", "", None));
+        assert!(condition.needs_update(status, "Reconciled", "all good", Some(4)));
+    }
+
+    #[test]
+    fn debounces_rapid_flips() {
+        let mut condition = Condition {
+            last_transition_time: epoch(),
+            ..Condition::unknown("Ready")
+        };
+        let interval = Duration::seconds(10);
+        let at = |seconds| FixedClock(DateTime::UNIX_EPOCH + Duration::seconds(seconds));
+
+        clock::with_clock(at(10), || {
+            assert!(condition.set_status_debounced(ConditionStatus::True, interval));
+        });
+        for seconds in 11..20 {
+            clock::with_clock(at(seconds), || {
+                assert!(!condition.set_status_debounced(ConditionStatus::False, interval));
+                assert!(!condition.set_all_debounced(ConditionStatus::False, "Flap", "", interval));
+            });
+        }
+        assert!(condition.is_true());
+        assert_eq!(
+            condition.age_at(DateTime::UNIX_EPOCH),
+            Duration::seconds(-10)
+        );
+
+        clock::with_clock(at(20), || {
+            assert!(condition.set_all_debounced(ConditionStatus::False, "Flap", "", interval));
+        });
+        assert!(condition.matches(ConditionStatus::False, "Flap"));
+    }
+
+    #[test]
+    fn most_recent_transition_wins() {
+        let mut condition = Condition {
+            last_transition_time: epoch(),
+            ..Condition::unknown("Ready").with_status(ConditionStatus::True)
+        };
+        let later = Time(DateTime::UNIX_EPOCH + Duration::minutes(1));
+        let mut other = Condition {
+            reason: "Timeout".to_string(),
+            last_transition_time: later.clone(),
+            ..Condition::unknown("Ready").with_status(ConditionStatus::False)
+        };
+
+        assert!(condition.merge(&other, MergePrecedence::MostRecent));
+        assert!(condition.matches(ConditionStatus::False, "Timeout"));
+        assert_eq!(condition.last_transition_time, later);
+
+        other.status = ConditionStatus::True.to_string();
+        other.last_transition_time = epoch();
+        assert!(!condition.merge(&other, MergePrecedence::MostRecent));
+        assert!(condition.is_false());
+    }
+
+    #[test]
+    fn worst_status_wins() {
+        let now = DateTime::UNIX_EPOCH + Duration::days(1);
+        clock::with_clock(FixedClock(now), || {
+            let mut condition = Condition {
+                last_transition_time: epoch(),
+                ..Condition::unknown("Ready").with_status(ConditionStatus::True)
+            };
+            let unknown = Condition::unknown("Ready").with_reason("Probing");
+            let failed = Condition::unknown("Ready")
+                .with_status(ConditionStatus::False)
+                .with_reason("Timeout");
+
+            assert!(condition.merge(&unknown, MergePrecedence::WorstStatus));
+            assert!(condition.matches(ConditionStatus::Unknown, "Probing"));
+            assert_eq!(condition.last_transition_time, Time(now));
+
+            assert!(condition.merge(&failed, MergePrecedence::WorstStatus));
+            assert!(condition.matches(ConditionStatus::False, "Timeout"));
+
+            assert!(!condition.merge(&unknown, MergePrecedence::WorstStatus));
+            assert!(condition.is_false());
+        });
+    }
+
+    #[test]
+    fn set_all_transitions_once() {
+        let mut dummy = dummy();
+        let mut condition = dummy.condition_mut("Ready");
+        condition.last_transition_time = epoch();
+
+        assert!(condition.set_all(ConditionStatus::True, ReadyReasons::Yoyo, "all good"));
+        assert!(condition.is_true());
+        assert!(condition.has_reason(ReadyReasons::Yoyo));
+        assert_eq!(condition.message, "all good");
+        assert_ne!(condition.last_transition_time, epoch());
+
+        condition.last_transition_time = epoch();
+        assert!(!condition.set_all(ConditionStatus::True, ReadyReasons::Yoyo, "all good"));
+        assert_eq!(condition.last_transition_time, epoch());
+
+        assert!(condition.set_all(ConditionStatus::True, ReadyReasons::Yoyo, "still good"));
+    }
+
+    #[test]
+    fn generation_tracks_resource() {
+        let mut dummy = dummy();
+        let resource = dummy.clone();
+        let mut condition = dummy.condition_mut("Ready");
+        assert!(!condition.is_current(&resource));
+
+        assert!(condition.set_generation_from(&resource));
+        assert!(condition.is_current(&resource));
+        assert!(!condition.set_generation_from(&resource));
+    }
+}
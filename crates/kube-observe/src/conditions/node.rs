@@ -0,0 +1,187 @@
+use k8s_openapi::api::core::v1::{Node, NodeCondition};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::standard::node;
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+use super::{ConditionExt, HasStatusConditions};
+
+impl_has_status_conditions!(
+    /// Mutating conditions is supported, but the built-in condition types such as `Ready` and
+    /// `MemoryPressure` are reported by the kubelet, which may overwrite any changes made to them.
+    Node,
+    status.conditions
+);
+
+/// Helpers for the standard conditions reported by the kubelet.
+///
+/// A missing or `Unknown` condition is never reported as `true`.
+pub trait NodeConditionExt {
+    /// Whether the `Ready` condition is `True`.
+    fn is_ready(&self) -> bool;
+
+    /// Whether the `MemoryPressure` condition is `True`, meaning the node is low on memory.
+    fn has_memory_pressure(&self) -> bool;
+
+    /// Whether the `DiskPressure` condition is `True`, meaning the node is low on disk.
+    fn has_disk_pressure(&self) -> bool;
+
+    /// Whether the `PIDPressure` condition is `True`, meaning the node is running too many processes.
+    fn has_pid_pressure(&self) -> bool;
+
+    /// Whether new pods can be scheduled on the node: it is ready, has not been cordoned, and is not
+    /// under any resource pressure.
+    fn is_schedulable(&self) -> bool;
+}
+
+impl NodeConditionExt for Node {
+    fn is_ready(&self) -> bool {
+        self.condition(node::READY).is_true()
+    }
+
+    fn has_memory_pressure(&self) -> bool {
+        self.condition(node::MEMORY_PRESSURE).is_true()
+    }
+
+    fn has_disk_pressure(&self) -> bool {
+        self.condition(node::DISK_PRESSURE).is_true()
+    }
+
+    fn has_pid_pressure(&self) -> bool {
+        self.condition(node::PID_PRESSURE).is_true()
+    }
+
+    fn is_schedulable(&self) -> bool {
+        let cordoned = self
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.unschedulable)
+            .unwrap_or(false);
+        self.is_ready()
+            && !cordoned
+            && !self.has_memory_pressure()
+            && !self.has_disk_pressure()
+            && !self.has_pid_pressure()
+    }
+}
+
+/// Node conditions are not tied to a generation, and `last_heartbeat_time` has no generic
+/// counterpart, so it is preserved when writing back.
+impl TypedCondition for NodeCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: None,
+            last_transition_time: transition_time(self.last_transition_time.as_ref()),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut node_condition = NodeCondition::default();
+        node_condition.write_back(condition);
+        node_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use k8s_openapi::api::core::v1::{NodeSpec, NodeStatus};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    use super::*;
+    use crate::conditions::TryHasStatusConditions;
+    use crate::conditions::testing::node;
+
+    #[test]
+    fn reads_node_conditions() {
+        let node = Node {
+            status: Some(NodeStatus {
+                conditions: Some(vec![NodeCondition {
+                    type_: "MemoryPressure".to_string(),
+                    status: "False".to_string(),
+                    reason: Some("KubeletHasSufficientMemory".to_string()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let memory_pressure = node.condition("MemoryPressure");
+        assert!(memory_pressure.is_false());
+        assert!(memory_pressure.has_reason("KubeletHasSufficientMemory"));
+        assert!(memory_pressure.observed_generation.is_none());
+        assert!(node.condition("Ready").is_unknown());
+        assert!(node.has_condition("MemoryPressure"));
+        assert!(!node.has_condition("Ready"));
+    }
+
+    #[test]
+    fn preserves_last_heartbeat_time() {
+        let heartbeat = Time(DateTime::UNIX_EPOCH);
+        let mut node = node(&[("Ready", "True"), ("MemoryPressure", "False")]);
+        node.status.as_mut().unwrap().conditions.as_mut().unwrap()[1].last_heartbeat_time =
+            Some(heartbeat.clone());
+
+        assert!(node.condition_mut("MemoryPressure").set_true());
+
+        let conditions = node.status.as_ref().unwrap().conditions.as_ref().unwrap();
+        assert_eq!(conditions[1].status, "True");
+        assert_eq!(conditions[1].last_heartbeat_time, Some(heartbeat));
+        assert!(node.has_memory_pressure());
+    }
+
+    #[test]
+    fn removes_conditions() {
+        let mut node = node(&[("Ready", "True"), ("MemoryPressure", "False")]);
+        assert!(node.try_condition_mut("Ready").is_ok());
+        assert!(node.remove_condition("Ready").unwrap().is_true());
+        assert_eq!(node.condition_types(), ["MemoryPressure"]);
+    }
+
+    #[test]
+    fn healthy_node_is_schedulable() {
+        let mut node = node(&[("Ready", "True"), ("MemoryPressure", "False")]);
+        assert!(node.is_ready());
+        assert!(!node.has_memory_pressure());
+        assert!(node.is_schedulable());
+
+        node.spec = Some(NodeSpec {
+            unschedulable: Some(true),
+            ..Default::default()
+        });
+        assert!(!node.is_schedulable());
+    }
+
+    #[test]
+    fn unready_node() {
+        let node = node(&[("Ready", "False")]);
+        assert!(!node.is_ready());
+        assert!(!node.is_schedulable());
+        assert!(!node.has_disk_pressure());
+    }
+
+    #[test]
+    fn node_under_memory_pressure() {
+        let node = node(&[("Ready", "True"), ("MemoryPressure", "True")]);
+        assert!(node.is_ready());
+        assert!(node.has_memory_pressure());
+        assert!(!node.has_pid_pressure());
+        assert!(!node.is_schedulable());
+    }
+}
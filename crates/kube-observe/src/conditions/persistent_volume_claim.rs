@@ -0,0 +1,73 @@
+use k8s_openapi::api::core::v1::{PersistentVolumeClaim, PersistentVolumeClaimCondition};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+
+impl_has_status_conditions!(PersistentVolumeClaim, status.conditions);
+
+/// PersistentVolumeClaim conditions are not tied to a generation. A missing
+/// `last_transition_time` falls back to `last_probe_time`, which is otherwise preserved when
+/// writing back.
+impl TypedCondition for PersistentVolumeClaimCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        let time = self
+            .last_transition_time
+            .as_ref()
+            .or(self.last_probe_time.as_ref());
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: None,
+            last_transition_time: transition_time(time),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut claim_condition = PersistentVolumeClaimCondition::default();
+        claim_condition.write_back(condition);
+        claim_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::core::v1::PersistentVolumeClaimStatus;
+
+    use super::*;
+    use crate::{ConditionExt, HasStatusConditions};
+
+    #[test]
+    fn reads_custom_condition() {
+        let claim = PersistentVolumeClaim {
+            status: Some(PersistentVolumeClaimStatus {
+                conditions: Some(vec![PersistentVolumeClaimCondition {
+                    type_: "FileSystemResizePending".to_string(),
+                    status: "False".to_string(),
+                    message: Some("waiting for node".to_string()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let resize_pending = claim.condition("FileSystemResizePending");
+        assert!(resize_pending.is_false());
+        assert_eq!(resize_pending.message, "waiting for node");
+        assert!(claim.condition("Resizing").is_unknown());
+    }
+}
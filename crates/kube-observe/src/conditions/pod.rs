@@ -0,0 +1,289 @@
+use k8s_openapi::api::core::v1::{Pod, PodCondition};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{Condition, Time};
+
+use super::standard::pod;
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+use super::{ConditionExt, HasStatusConditions};
+
+impl_has_status_conditions!(Pod, status.conditions);
+
+/// Helpers for the standard conditions reported by the kubelet.
+///
+/// A missing or `Unknown` condition is never reported as `true`.
+pub trait PodConditionExt {
+    /// Whether the `Ready` condition is `True`.
+    fn is_ready(&self) -> bool;
+
+    /// Whether the `Initialized` condition is `True`.
+    fn is_initialized(&self) -> bool;
+
+    /// Whether the `ContainersReady` condition is `True`.
+    fn containers_ready(&self) -> bool;
+
+    /// Whether the `PodScheduled` condition is `True`.
+    fn is_scheduled(&self) -> bool;
+
+    /// The last time the condition of the given type was probed.
+    ///
+    /// Generic [`Condition`]s have no such field, so this reads it from the pod condition directly.
+    fn last_probe_time(&self, type_: impl ToString) -> Option<Time>;
+}
+
+impl PodConditionExt for Pod {
+    fn is_ready(&self) -> bool {
+        self.condition(pod::READY).is_true()
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.condition(pod::INITIALIZED).is_true()
+    }
+
+    fn containers_ready(&self) -> bool {
+        self.condition(pod::CONTAINERS_READY).is_true()
+    }
+
+    fn is_scheduled(&self) -> bool {
+        self.condition(pod::POD_SCHEDULED).is_true()
+    }
+
+    fn last_probe_time(&self, type_: impl ToString) -> Option<Time> {
+        let type_ = type_.to_string();
+        self.status
+            .as_ref()?
+            .conditions
+            .as_ref()?
+            .iter()
+            .find(|condition| condition.type_ == type_)?
+            .last_probe_time
+            .clone()
+    }
+}
+
+/// `last_probe_time` has no generic counterpart, so it is preserved when writing back.
+impl TypedCondition for PodCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: self.observed_generation,
+            last_transition_time: transition_time(self.last_transition_time.as_ref()),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.observed_generation = condition.observed_generation;
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut pod_condition = PodCondition::default();
+        pod_condition.write_back(condition);
+        pod_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use k8s_openapi::api::core::v1::PodStatus;
+
+    use super::*;
+    use crate::ConditionStatus;
+
+    fn pod(conditions: Vec<PodCondition>) -> Pod {
+        Pod {
+            status: Some(PodStatus {
+                conditions: Some(conditions),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reads_pod_conditions() {
+        let pod = pod(vec![PodCondition {
+            type_: "Ready".to_string(),
+            status: "True".to_string(),
+            reason: Some("PodCompleted".to_string()),
+            ..Default::default()
+        }]);
+
+        let ready = pod.condition("Ready");
+        assert!(ready.is_true());
+        assert!(ready.has_reason("PodCompleted"));
+        assert!(pod.condition("Initialized").is_unknown());
+        assert_eq!(pod.condition_types(), ["Ready"]);
+    }
+
+    #[test]
+    fn converts_every_pod_condition() {
+        let pod = pod(vec![
+            PodCondition {
+                type_: "PodScheduled".to_string(),
+                status: "True".to_string(),
+                ..Default::default()
+            },
+            PodCondition {
+                type_: "Initialized".to_string(),
+                status: "True".to_string(),
+                ..Default::default()
+            },
+            PodCondition {
+                type_: "Ready".to_string(),
+                status: "False".to_string(),
+                message: Some("containers with unready status".to_string()),
+                ..Default::default()
+            },
+        ]);
+
+        let conditions = pod.conditions();
+        let types: Vec<_> = conditions
+            .iter()
+            .map(|condition| condition.type_.as_str())
+            .collect();
+        assert_eq!(types, ["PodScheduled", "Initialized", "Ready"]);
+        assert!(conditions[2].is_false());
+        assert_eq!(conditions[2].message, "containers with unready status");
+    }
+
+    #[test]
+    fn initialized_but_not_ready() {
+        let pod = pod(vec![
+            PodCondition {
+                type_: "Ready".to_string(),
+                status: "False".to_string(),
+                ..Default::default()
+            },
+            PodCondition {
+                type_: "Initialized".to_string(),
+                status: "True".to_string(),
+                ..Default::default()
+            },
+            PodCondition {
+                type_: "PodScheduled".to_string(),
+                status: "Unknown".to_string(),
+                ..Default::default()
+            },
+        ]);
+
+        assert!(!pod.is_ready());
+        assert!(pod.is_initialized());
+        assert!(!pod.containers_ready());
+        assert!(!pod.is_scheduled());
+    }
+
+    #[test]
+    fn sets_readiness_gate_condition() {
+        let mut pod = Pod::default();
+        {
+            let mut gate = pod.condition_mut("example.com/feature-1");
+            assert!(gate.set_all(ConditionStatus::True, "FeatureEnabled", "rolled out"));
+        }
+
+        let conditions = pod.status.unwrap().conditions.unwrap();
+        assert_eq!(conditions.len(), 1);
+        assert_eq!(conditions[0].type_, "example.com/feature-1");
+        assert_eq!(conditions[0].status, "True");
+        assert_eq!(conditions[0].reason.as_deref(), Some("FeatureEnabled"));
+        assert_eq!(conditions[0].message.as_deref(), Some("rolled out"));
+    }
+
+    #[test]
+    fn preserves_last_probe_time() {
+        let probed = Time(DateTime::UNIX_EPOCH);
+        let mut pod = pod(vec![PodCondition {
+            type_: "example.com/feature-1".to_string(),
+            status: "False".to_string(),
+            last_probe_time: Some(probed.clone()),
+            ..Default::default()
+        }]);
+
+        pod.condition_mut("example.com/feature-1").set_true();
+
+        let conditions = pod.status.unwrap().conditions.unwrap();
+        assert_eq!(conditions.len(), 1);
+        assert_eq!(conditions[0].status, "True");
+        assert_eq!(conditions[0].last_probe_time, Some(probed));
+    }
+
+    #[test]
+    fn writes_back_whole_list() {
+        let probed = Time(DateTime::UNIX_EPOCH);
+        let mut pod = pod(vec![
+            PodCondition {
+                type_: "example.com/feature-1".to_string(),
+                status: "False".to_string(),
+                last_probe_time: Some(probed.clone()),
+                ..Default::default()
+            },
+            PodCondition {
+                type_: "Ready".to_string(),
+                status: "True".to_string(),
+                ..Default::default()
+            },
+        ]);
+
+        {
+            let mut conditions = pod.conditions_mut();
+            conditions[0].set_true();
+            conditions.retain(|condition| condition.type_ != "Ready");
+            conditions.push(Condition::unknown("example.com/feature-2"));
+        }
+
+        let conditions = pod.status.unwrap().conditions.unwrap();
+        assert_eq!(conditions.len(), 2);
+        assert_eq!(conditions[0].status, "True");
+        assert_eq!(conditions[0].last_probe_time, Some(probed));
+        assert_eq!(conditions[1].type_, "example.com/feature-2");
+        assert_eq!(conditions[1].status, "Unknown");
+    }
+
+    #[test]
+    fn reads_last_probe_time() {
+        let probed = Time(DateTime::UNIX_EPOCH);
+        let pod = pod(vec![
+            PodCondition {
+                type_: "example.com/feature-1".to_string(),
+                status: "True".to_string(),
+                last_probe_time: Some(probed.clone()),
+                ..Default::default()
+            },
+            PodCondition {
+                type_: "Ready".to_string(),
+                status: "True".to_string(),
+                ..Default::default()
+            },
+        ]);
+
+        assert_eq!(pod.last_probe_time("example.com/feature-1"), Some(probed));
+        assert_eq!(pod.last_probe_time("Ready"), None);
+        assert_eq!(pod.last_probe_time("Initialized"), None);
+        assert_eq!(Pod::default().last_probe_time("Ready"), None);
+    }
+
+    #[test]
+    fn unchanged_condition_is_not_written_back() {
+        let mut pod = pod(vec![PodCondition {
+            type_: "Ready".to_string(),
+            status: "True".to_string(),
+            ..Default::default()
+        }]);
+
+        pod.condition_mut("Ready").set_true();
+
+        let conditions = pod.status.unwrap().conditions.unwrap();
+        assert_eq!(conditions[0].last_transition_time, None);
+        assert_eq!(conditions[0].reason, None);
+    }
+}
@@ -0,0 +1,67 @@
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::ConditionAccessor;
+
+/// PodDisruptionBudget conditions are generic [`Condition`]s, so their observed generation is
+/// preserved and [`ConditionExt::is_current`](super::ConditionExt::is_current) is meaningful.
+impl ConditionAccessor for PodDisruptionBudget {
+    fn conditions(&self) -> Option<&Vec<Condition>> {
+        self.status.as_ref()?.conditions.as_ref()
+    }
+
+    fn conditions_mut(&mut self) -> &mut Vec<Condition> {
+        self.status
+            .get_or_insert_with(Default::default)
+            .conditions
+            .get_or_insert_with(Vec::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::policy::v1::PodDisruptionBudgetStatus;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    use super::*;
+    use crate::conditions::standard::pod_disruption_budget;
+    use crate::conditions::testing::epoch;
+    use crate::{ConditionExt, HasStatusConditions};
+
+    fn budget(generation: i64, observed_generation: i64) -> PodDisruptionBudget {
+        PodDisruptionBudget {
+            metadata: ObjectMeta {
+                generation: Some(generation),
+                ..Default::default()
+            },
+            status: Some(PodDisruptionBudgetStatus {
+                conditions: Some(vec![Condition {
+                    type_: "DisruptionAllowed".to_string(),
+                    status: "True".to_string(),
+                    reason: "SufficientPods".to_string(),
+                    message: String::new(),
+                    observed_generation: Some(observed_generation),
+                    last_transition_time: epoch(),
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn disruption_allowed_tracks_generation() {
+        let current = budget(2, 2);
+        let allowed = current.condition(pod_disruption_budget::DISRUPTION_ALLOWED);
+        assert!(allowed.is_true());
+        assert!(allowed.has_reason("SufficientPods"));
+        assert!(allowed.is_current(&current));
+
+        let outdated = budget(3, 2);
+        assert!(
+            !outdated
+                .condition(pod_disruption_budget::DISRUPTION_ALLOWED)
+                .is_current(&outdated)
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+
+use super::standard::{self, deployment, node, pod};
+use super::{ConditionExt, HasStatusConditions};
+
+/// Which status of each condition type means the resource is healthy.
+///
+/// Most conditions are healthy when `True`, like `Ready`, but some report a problem when `True`,
+/// like the `MemoryPressure` condition of nodes. Types that have not been registered are assumed
+/// to be healthy when `True`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConditionPolicy {
+    true_is_healthy: BTreeMap<String, bool>,
+}
+
+/// Covers the standard conditions of nodes, pods, and deployments, and the `Degraded` condition.
+impl Default for ConditionPolicy {
+    fn default() -> Self {
+        let mut policy = Self::empty();
+        for type_ in [
+            standard::READY,
+            standard::AVAILABLE,
+            standard::PROGRESSING,
+            pod::INITIALIZED,
+            pod::CONTAINERS_READY,
+            pod::POD_SCHEDULED,
+            pod::POD_READY_TO_START_CONTAINERS,
+        ] {
+            policy.register(type_, true);
+        }
+        for type_ in [
+            standard::DEGRADED,
+            node::MEMORY_PRESSURE,
+            node::DISK_PRESSURE,
+            node::PID_PRESSURE,
+            node::NETWORK_UNAVAILABLE,
+            pod::DISRUPTION_TARGET,
+            deployment::REPLICA_FAILURE,
+        ] {
+            policy.register(type_, false);
+        }
+        policy
+    }
+}
+
+impl ConditionPolicy {
+    /// Creates a policy with no registered types, so every condition is healthy when `True`.
+    pub fn empty() -> Self {
+        Self {
+            true_is_healthy: BTreeMap::new(),
+        }
+    }
+
+    /// Registers whether the condition of the given type is healthy when `True` or when `False`,
+    /// replacing any previous entry for the type.
+    pub fn register(&mut self, type_: impl ToString, true_is_healthy: bool) -> &mut Self {
+        self.true_is_healthy
+            .insert(type_.to_string(), true_is_healthy);
+        self
+    }
+
+    /// Whether the condition of the given type is healthy when `True`.
+    pub fn true_is_healthy(&self, type_: impl ToString) -> bool {
+        self.true_is_healthy
+            .get(&type_.to_string())
+            .copied()
+            .unwrap_or(true)
+    }
+
+    /// Whether the condition of the given type reports the resource as healthy.
+    ///
+    /// A missing or `Unknown` condition is never healthy.
+    pub fn is_healthy(&self, resource: &impl HasStatusConditions, type_: impl ToString) -> bool {
+        let type_ = type_.to_string();
+        resource.condition(&type_).as_bool() == Some(self.true_is_healthy(&type_))
+    }
+}
+
+/// Whether the condition of the given type reports the resource as healthy, according to the
+/// [default](ConditionPolicy::default) policy.
+pub fn is_healthy(resource: &impl HasStatusConditions, type_: impl ToString) -> bool {
+    ConditionPolicy::default().is_healthy(resource, type_)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conditions::testing::node;
+
+    #[test]
+    fn pressure_is_unhealthy_when_true() {
+        let pressured = node(&[("Ready", "True"), ("MemoryPressure", "True")]);
+        assert!(is_healthy(&pressured, "Ready"));
+        assert!(!is_healthy(&pressured, "MemoryPressure"));
+
+        let relieved = node(&[("MemoryPressure", "False"), ("DiskPressure", "Unknown")]);
+        assert!(is_healthy(&relieved, "MemoryPressure"));
+        assert!(!is_healthy(&relieved, "DiskPressure"));
+        assert!(!is_healthy(&relieved, "Ready"));
+    }
+
+    #[test]
+    fn registers_custom_types() {
+        let node = node(&[
+            ("example.com/Overheating", "False"),
+            ("MemoryPressure", "True"),
+        ]);
+        let mut policy = ConditionPolicy::default();
+        assert!(!policy.is_healthy(&node, "example.com/Overheating"));
+
+        policy.register("example.com/Overheating", false);
+        assert!(policy.is_healthy(&node, "example.com/Overheating"));
+        assert!(ConditionPolicy::empty().is_healthy(&node, "MemoryPressure"));
+    }
+}
@@ -0,0 +1,48 @@
+/// A machine-readable reason for a condition, usually an enum with one variant per reason.
+///
+/// Implement it alongside [`FromStr`](std::str::FromStr) so reasons can be round-tripped through
+/// [`ConditionExt::set_reason_typed`](super::ConditionExt::set_reason_typed) and
+/// [`ConditionExt::reason_is`](super::ConditionExt::reason_is):
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use k8s_openapi::api::core::v1::Pod;
+/// use kube_observe::conditions::ConditionReason;
+/// use kube_observe::{ConditionExt, HasStatusConditions};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum ReadyReason {
+///     Reconciled,
+///     Waiting,
+/// }
+///
+/// impl ConditionReason for ReadyReason {
+///     fn as_str(&self) -> &'static str {
+///         match self {
+///             ReadyReason::Reconciled => "Reconciled",
+///             ReadyReason::Waiting => "Waiting",
+///         }
+///     }
+/// }
+///
+/// impl FromStr for ReadyReason {
+///     type Err = ();
+///
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         match s {
+///             "Reconciled" => Ok(ReadyReason::Reconciled),
+///             "Waiting" => Ok(ReadyReason::Waiting),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// let mut pod = Pod::default();
+/// pod.condition_mut("Ready").set_reason_typed(ReadyReason::Waiting);
+/// assert_eq!(pod.condition("Ready").reason_is(), Some(ReadyReason::Waiting));
+/// ```
+pub trait ConditionReason {
+    /// The string stored in the `reason` field of the condition.
+    fn as_str(&self) -> &'static str;
+}
@@ -0,0 +1,83 @@
+use k8s_openapi::api::apps::v1::{ReplicaSet, ReplicaSetCondition};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+
+impl_has_status_conditions!(ReplicaSet, status.conditions);
+
+/// ReplicaSet conditions are not tied to a generation.
+impl TypedCondition for ReplicaSetCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: None,
+            last_transition_time: transition_time(self.last_transition_time.as_ref()),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut replica_set_condition = ReplicaSetCondition::default();
+        replica_set_condition.write_back(condition);
+        replica_set_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::apps::v1::ReplicaSetStatus;
+
+    use super::*;
+    use crate::{ConditionExt, HasStatusConditions};
+
+    #[test]
+    fn reads_custom_condition() {
+        let replica_set = ReplicaSet {
+            status: Some(ReplicaSetStatus {
+                conditions: Some(vec![ReplicaSetCondition {
+                    type_: "ReplicaFailure".to_string(),
+                    status: "True".to_string(),
+                    reason: Some("FailedCreate".to_string()),
+                    message: Some("exceeded quota".to_string()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let condition = replica_set.condition("ReplicaFailure");
+        assert!(condition.is_true());
+        assert!(condition.has_reason("FailedCreate"));
+        assert_eq!(condition.message, "exceeded quota");
+        assert!(condition.observed_generation.is_none());
+        assert!(replica_set.condition("Ready").is_unknown());
+    }
+
+    #[test]
+    fn writes_custom_condition() {
+        let mut replica_set = ReplicaSet::default();
+        replica_set
+            .condition_mut("example.com/Scaled")
+            .set_reason("Manual");
+
+        let conditions = replica_set.status.unwrap().conditions.unwrap();
+        assert_eq!(conditions.len(), 1);
+        assert_eq!(conditions[0].status, "Unknown");
+        assert_eq!(conditions[0].reason.as_deref(), Some("Manual"));
+    }
+}
@@ -0,0 +1,61 @@
+use k8s_openapi::api::core::v1::Service;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::ConditionAccessor;
+
+/// Service conditions are generic [`Condition`]s, set by the controllers that provision load
+/// balancers for the service.
+impl ConditionAccessor for Service {
+    fn conditions(&self) -> Option<&Vec<Condition>> {
+        self.status.as_ref()?.conditions.as_ref()
+    }
+
+    fn conditions_mut(&mut self) -> &mut Vec<Condition> {
+        self.status
+            .get_or_insert_with(Default::default)
+            .conditions
+            .get_or_insert_with(Vec::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::core::v1::ServiceStatus;
+
+    use super::*;
+    use crate::{ConditionExt, HasStatusConditions};
+
+    #[test]
+    fn reads_custom_condition() {
+        let service = Service {
+            status: Some(ServiceStatus {
+                conditions: Some(vec![Condition {
+                    type_: "example.com/LoadBalancerReady".to_string(),
+                    status: "False".to_string(),
+                    reason: "Provisioning".to_string(),
+                    ..Condition::unknown("")
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let condition = service.condition("example.com/LoadBalancerReady");
+        assert!(condition.is_false());
+        assert!(condition.has_reason("Provisioning"));
+        assert!(service.condition("Ready").is_unknown());
+        assert!(Service::default().condition("Ready").is_unknown());
+    }
+
+    #[test]
+    fn writes_custom_condition() {
+        let mut service = Service::default();
+        service
+            .condition_mut("example.com/LoadBalancerReady")
+            .set_true();
+
+        let conditions = service.status.unwrap().conditions.unwrap();
+        assert_eq!(conditions.len(), 1);
+        assert!(conditions[0].is_true());
+    }
+}
@@ -0,0 +1,157 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+/// A set of conditions keyed by type.
+///
+/// The conditions are always kept sorted by type, so iteration order is deterministic regardless of
+/// the order in which they were inserted.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConditionSet(Vec<Condition>);
+
+impl ConditionSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts the condition, replacing and returning any existing condition of the same type.
+    pub fn upsert(&mut self, condition: Condition) -> Option<Condition> {
+        match self.position(&condition.type_) {
+            Ok(index) => Some(std::mem::replace(&mut self.0[index], condition)),
+            Err(index) => {
+                self.0.insert(index, condition);
+                None
+            }
+        }
+    }
+
+    /// Returns the condition of the given type, if present.
+    pub fn get(&self, type_: impl ToString) -> Option<&Condition> {
+        let index = self.position(&type_.to_string()).ok()?;
+        Some(&self.0[index])
+    }
+
+    /// Removes the condition of the given type, returning it if it was present.
+    pub fn remove(&mut self, type_: impl ToString) -> Option<Condition> {
+        let index = self.position(&type_.to_string()).ok()?;
+        Some(self.0.remove(index))
+    }
+
+    /// Iterates over the conditions in order of type.
+    pub fn iter(&self) -> std::slice::Iter<'_, Condition> {
+        self.0.iter()
+    }
+
+    /// The number of conditions in the set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the set has no conditions.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn position(&self, type_: &str) -> Result<usize, usize> {
+        self.0
+            .binary_search_by(|condition| condition.type_.as_str().cmp(type_))
+    }
+}
+
+/// Sorts the conditions by type. If several conditions share a type, the last one wins, as if they
+/// had been upserted in order.
+impl From<Vec<Condition>> for ConditionSet {
+    fn from(conditions: Vec<Condition>) -> Self {
+        conditions.into_iter().collect()
+    }
+}
+
+impl From<ConditionSet> for Vec<Condition> {
+    fn from(set: ConditionSet) -> Self {
+        set.0
+    }
+}
+
+impl FromIterator<Condition> for ConditionSet {
+    fn from_iter<I: IntoIterator<Item = Condition>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for condition in iter {
+            set.upsert(condition);
+        }
+        set
+    }
+}
+
+impl IntoIterator for ConditionSet {
+    type Item = Condition;
+    type IntoIter = std::vec::IntoIter<Condition>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ConditionSet {
+    type Item = &'a Condition;
+    type IntoIter = std::slice::Iter<'a, Condition>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConditionExt, ConditionStatus};
+
+    fn types(set: &ConditionSet) -> Vec<&str> {
+        set.iter()
+            .map(|condition| condition.type_.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn upsert_replaces_rather_than_duplicates() {
+        let mut set = ConditionSet::new();
+        assert!(set.upsert(Condition::unknown("Ready")).is_none());
+
+        let mut ready = Condition::unknown("Ready");
+        ready.set_status(ConditionStatus::True);
+        let previous = set.upsert(ready).unwrap();
+
+        assert!(previous.is_unknown());
+        assert_eq!(set.len(), 1);
+        assert!(set.get("Ready").unwrap().is_true());
+    }
+
+    #[test]
+    fn orders_by_type() {
+        let mut set = ConditionSet::new();
+        for type_ in ["Ready", "Available", "Progressing"] {
+            set.upsert(Condition::unknown(type_));
+        }
+        assert_eq!(types(&set), ["Available", "Progressing", "Ready"]);
+
+        assert_eq!(set.remove("Progressing").unwrap().type_, "Progressing");
+        assert!(set.remove("Progressing").is_none());
+        assert!(set.get("Progressing").is_none());
+        assert_eq!(types(&set), ["Available", "Ready"]);
+    }
+
+    #[test]
+    fn converts_from_unsorted_vec() {
+        let mut duplicate = Condition::unknown("Ready");
+        duplicate.set_false();
+        let set = ConditionSet::from(vec![
+            Condition::unknown("Ready"),
+            Condition::unknown("Available"),
+            duplicate,
+        ]);
+
+        assert_eq!(types(&set), ["Available", "Ready"]);
+        assert!(set.get("Ready").unwrap().is_false());
+
+        let conditions: Vec<Condition> = set.into();
+        assert_eq!(conditions.len(), 2);
+    }
+}
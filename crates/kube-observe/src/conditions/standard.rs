@@ -0,0 +1,124 @@
+//! Canonical condition types.
+//!
+//! The types shared across many resources are defined at the top level, while the types specific
+//! to a kind of resource are grouped in a module named after it, along with any reasons its
+//! controller gives them that carry a meaning of their own.
+
+/// The resource is ready to serve its purpose.
+pub const READY: &str = "Ready";
+
+/// The resource is available to its consumers.
+pub const AVAILABLE: &str = "Available";
+
+/// The resource is being rolled out or reconciled.
+pub const PROGRESSING: &str = "Progressing";
+
+/// The resource is running, but not as well as it should.
+pub const DEGRADED: &str = "Degraded";
+
+/// Condition types reported by the kubelet on nodes.
+pub mod node {
+    pub use super::READY;
+
+    /// The node is running low on memory.
+    pub const MEMORY_PRESSURE: &str = "MemoryPressure";
+
+    /// The node is running low on disk space.
+    pub const DISK_PRESSURE: &str = "DiskPressure";
+
+    /// The node is running too many processes.
+    pub const PID_PRESSURE: &str = "PIDPressure";
+
+    /// The network of the node is not configured correctly.
+    pub const NETWORK_UNAVAILABLE: &str = "NetworkUnavailable";
+}
+
+/// Condition types reported by the kubelet on pods.
+pub mod pod {
+    pub use super::READY;
+
+    /// All init containers have completed successfully.
+    pub const INITIALIZED: &str = "Initialized";
+
+    /// All containers in the pod are ready.
+    pub const CONTAINERS_READY: &str = "ContainersReady";
+
+    /// The pod has been scheduled to a node.
+    pub const POD_SCHEDULED: &str = "PodScheduled";
+
+    /// The sandbox of the pod has been created and its networking configured.
+    pub const POD_READY_TO_START_CONTAINERS: &str = "PodReadyToStartContainers";
+
+    /// The pod is about to be terminated because of a disruption.
+    pub const DISRUPTION_TARGET: &str = "DisruptionTarget";
+}
+
+/// Condition types reported by the deployment controller.
+pub mod deployment {
+    pub use super::{AVAILABLE, PROGRESSING};
+
+    /// A replica set could not create or delete pods.
+    pub const REPLICA_FAILURE: &str = "ReplicaFailure";
+
+    /// The reason of a `True` `Progressing` condition once the new replica set has fully rolled
+    /// out.
+    pub const NEW_REPLICA_SET_AVAILABLE: &str = "NewReplicaSetAvailable";
+
+    /// The reason of a `False` `Progressing` condition once the rollout has taken longer than the
+    /// progress deadline of the deployment.
+    pub const PROGRESS_DEADLINE_EXCEEDED: &str = "ProgressDeadlineExceeded";
+}
+
+/// Condition types reported by the job controller.
+pub mod job {
+    /// The job has completed successfully.
+    pub const COMPLETE: &str = "Complete";
+
+    /// The job has failed.
+    pub const FAILED: &str = "Failed";
+
+    /// The job has been suspended.
+    pub const SUSPENDED: &str = "Suspended";
+
+    /// A pod of the job has failed and matched a failure policy rule.
+    pub const FAILURE_TARGET: &str = "FailureTarget";
+
+    /// The job has met its success policy.
+    pub const SUCCESS_CRITERIA_MET: &str = "SuccessCriteriaMet";
+}
+
+/// Condition types of the kstatus conventions, which many custom resources follow.
+pub mod kstatus {
+    /// The controller is still working towards the desired state of the resource.
+    pub const RECONCILING: &str = "Reconciling";
+
+    /// The controller cannot make progress towards the desired state of the resource.
+    pub const STALLED: &str = "Stalled";
+}
+
+/// Condition types reported by the horizontal pod autoscaler controller.
+pub mod horizontal_pod_autoscaler {
+    /// The autoscaler can fetch and update the scale of its target.
+    pub const ABLE_TO_SCALE: &str = "AbleToScale";
+
+    /// The autoscaler can compute a replica count from its metrics.
+    pub const SCALING_ACTIVE: &str = "ScalingActive";
+
+    /// The desired replica count was clamped to the bounds of the autoscaler.
+    pub const SCALING_LIMITED: &str = "ScalingLimited";
+}
+
+/// Condition types reported by the disruption controller on pod disruption budgets.
+pub mod pod_disruption_budget {
+    /// Enough pods are healthy for the budget to allow at least one disruption.
+    pub const DISRUPTION_ALLOWED: &str = "DisruptionAllowed";
+}
+
+/// Condition types reported on persistent volume claims.
+pub mod persistent_volume_claim {
+    /// The volume is being resized.
+    pub const RESIZING: &str = "Resizing";
+
+    /// The volume has been resized but the file system still needs to be resized on the node.
+    pub const FILE_SYSTEM_RESIZE_PENDING: &str = "FileSystemResizePending";
+}
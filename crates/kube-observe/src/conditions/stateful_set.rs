@@ -0,0 +1,66 @@
+use k8s_openapi::api::apps::v1::{StatefulSet, StatefulSetCondition};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::typed::{TypedCondition, impl_has_status_conditions, non_empty, transition_time};
+
+impl_has_status_conditions!(StatefulSet, status.conditions);
+
+/// StatefulSet conditions are not tied to a generation.
+impl TypedCondition for StatefulSetCondition {
+    fn type_(&self) -> &str {
+        &self.type_
+    }
+
+    fn project(&self) -> Condition {
+        Condition {
+            type_: self.type_.clone(),
+            status: self.status.clone(),
+            reason: self.reason.clone().unwrap_or_default(),
+            message: self.message.clone().unwrap_or_default(),
+            observed_generation: None,
+            last_transition_time: transition_time(self.last_transition_time.as_ref()),
+        }
+    }
+
+    fn write_back(&mut self, condition: &Condition) {
+        self.type_ = condition.type_.clone();
+        self.status = condition.status.clone();
+        self.reason = non_empty(&condition.reason);
+        self.message = non_empty(&condition.message);
+        self.last_transition_time = Some(condition.last_transition_time.clone());
+    }
+
+    fn from_condition(condition: &Condition) -> Self {
+        let mut stateful_set_condition = StatefulSetCondition::default();
+        stateful_set_condition.write_back(condition);
+        stateful_set_condition
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::apps::v1::StatefulSetStatus;
+
+    use super::*;
+    use crate::{ConditionExt, HasStatusConditions};
+
+    #[test]
+    fn reads_custom_condition() {
+        let stateful_set = StatefulSet {
+            status: Some(StatefulSetStatus {
+                conditions: Some(vec![StatefulSetCondition {
+                    type_: "example.com/RolloutComplete".to_string(),
+                    status: "True".to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let condition = stateful_set.condition("example.com/RolloutComplete");
+        assert!(condition.is_true());
+        assert!(condition.observed_generation.is_none());
+        assert!(stateful_set.condition("Ready").is_unknown());
+    }
+}
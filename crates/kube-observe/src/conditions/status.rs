@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The status of a condition, as defined by the kubernetes API conventions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConditionStatus {
+    True,
+    False,
+    Unknown,
+}
+
+impl ConditionStatus {
+    /// The canonical string representation of the status.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ConditionStatus::True => "True",
+            ConditionStatus::False => "False",
+            ConditionStatus::Unknown => "Unknown",
+        }
+    }
+
+    /// The opposite status, swapping `True` and `False` and leaving `Unknown` as is.
+    pub const fn invert(self) -> ConditionStatus {
+        match self {
+            ConditionStatus::True => ConditionStatus::False,
+            ConditionStatus::False => ConditionStatus::True,
+            ConditionStatus::Unknown => ConditionStatus::Unknown,
+        }
+    }
+
+    /// Converts the status into a boolean, or `None` if it is `Unknown`.
+    pub const fn as_bool(self) -> Option<bool> {
+        match self {
+            ConditionStatus::True => Some(true),
+            ConditionStatus::False => Some(false),
+            ConditionStatus::Unknown => None,
+        }
+    }
+
+    /// `True` if `value` is `true`, `False` otherwise.
+    pub const fn from_bool(value: bool) -> ConditionStatus {
+        if value {
+            ConditionStatus::True
+        } else {
+            ConditionStatus::False
+        }
+    }
+}
+
+/// Statuses are ordered by health: `False < Unknown < True`, so the greatest status is the
+/// healthiest. Use [`Reverse`](std::cmp::Reverse) to order them by severity instead.
+impl Ord for ConditionStatus {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let health = |status: ConditionStatus| match status {
+            ConditionStatus::False => 0,
+            ConditionStatus::Unknown => 1,
+            ConditionStatus::True => 2,
+        };
+        health(*self).cmp(&health(*other))
+    }
+}
+
+impl PartialOrd for ConditionStatus {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for ConditionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parsing never fails; any string other than `True` or `False` is treated as `Unknown`.
+impl FromStr for ConditionStatus {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "True" => ConditionStatus::True,
+            "False" => ConditionStatus::False,
+            _ => ConditionStatus::Unknown,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_canonical_strings() {
+        for status in [
+            ConditionStatus::True,
+            ConditionStatus::False,
+            ConditionStatus::Unknown,
+        ] {
+            assert_eq!(status.to_string().parse(), Ok(status));
+        }
+    }
+
+    #[test]
+    fn inverts_known_statuses() {
+        assert_eq!(ConditionStatus::True.invert(), ConditionStatus::False);
+        assert_eq!(ConditionStatus::False.invert(), ConditionStatus::True);
+        assert_eq!(ConditionStatus::Unknown.invert(), ConditionStatus::Unknown);
+    }
+
+    #[test]
+    fn converts_to_and_from_bool() {
+        assert_eq!(ConditionStatus::True.as_bool(), Some(true));
+        assert_eq!(ConditionStatus::False.as_bool(), Some(false));
+        assert_eq!(ConditionStatus::Unknown.as_bool(), None);
+
+        assert_eq!(ConditionStatus::from_bool(true), ConditionStatus::True);
+        assert_eq!(ConditionStatus::from_bool(false), ConditionStatus::False);
+    }
+
+    #[test]
+    fn orders_by_health() {
+        assert!(ConditionStatus::False < ConditionStatus::Unknown);
+        assert!(ConditionStatus::Unknown < ConditionStatus::True);
+        assert!(ConditionStatus::False < ConditionStatus::True);
+
+        let mut statuses = [
+            ConditionStatus::True,
+            ConditionStatus::Unknown,
+            ConditionStatus::False,
+        ];
+        statuses.sort();
+        assert_eq!(
+            statuses,
+            [
+                ConditionStatus::False,
+                ConditionStatus::Unknown,
+                ConditionStatus::True
+            ]
+        );
+    }
+
+    #[test]
+    fn unexpected_strings_are_unknown() {
+        assert_eq!("true".parse(), Ok(ConditionStatus::Unknown));
+        assert_eq!("".parse(), Ok(ConditionStatus::Unknown));
+    }
+}
@@ -0,0 +1,61 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::{ConditionExt, HasStatusConditions};
+
+/// Flattens the conditions of a resource into a map from type to status, such as
+/// `{ "Progressing": "False", "Ready": "True" }`, for config files and golden tests.
+pub fn conditions_to_status_map(resource: &impl HasStatusConditions) -> BTreeMap<String, String> {
+    resource
+        .conditions()
+        .into_iter()
+        .map(|condition| (condition.type_, condition.status))
+        .collect()
+}
+
+/// Builds conditions from a map from type to status, the inverse of [`conditions_to_status_map`].
+///
+/// The conditions have no reason or message, and transitioned at the current time of the
+/// [clock](crate::clock).
+pub fn conditions_from_status_map(map: &BTreeMap<String, String>) -> Vec<Condition> {
+    map.iter()
+        .map(|(type_, status)| Condition {
+            status: status.clone(),
+            ..Condition::unknown(type_)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    use super::*;
+    use crate::clock::{self, FixedClock};
+    use crate::conditions::testing::{DummyStatus, dummy};
+
+    #[test]
+    fn round_trips_type_to_status() {
+        let map = BTreeMap::from([
+            ("Progressing".to_string(), "False".to_string()),
+            ("Ready".to_string(), "True".to_string()),
+        ]);
+
+        let conditions = clock::with_clock(FixedClock(DateTime::UNIX_EPOCH), || {
+            conditions_from_status_map(&map)
+        });
+        assert!(
+            conditions
+                .iter()
+                .all(|condition| condition.last_transition_time == Time(DateTime::UNIX_EPOCH))
+        );
+
+        let mut dummy = dummy();
+        dummy.status = Some(DummyStatus {
+            conditions: Some(conditions),
+        });
+        assert_eq!(conditions_to_status_map(&dummy), map);
+    }
+}
@@ -0,0 +1,67 @@
+use kube::Resource;
+
+use super::{ConditionExt, HasStatusConditions};
+
+/// Renders an aligned text table with one row per resource and one column per condition type,
+/// like the output of `kubectl get`.
+///
+/// Each cell shows the status of the condition, with missing conditions shown as `Unknown`.
+/// Columns are separated by two spaces and padded to their widest cell.
+pub fn render_condition_table<R: HasStatusConditions + Resource>(
+    resources: &[R],
+    types: &[&str],
+) -> String {
+    let header = std::iter::once("NAME".to_string())
+        .chain(types.iter().map(|type_| type_.to_uppercase()))
+        .collect();
+    let rows: Vec<Vec<String>> = std::iter::once(header)
+        .chain(resources.iter().map(|resource| {
+            let name = resource.meta().name.clone().unwrap_or_default();
+            std::iter::once(name)
+                .chain(
+                    types
+                        .iter()
+                        .map(|type_| resource.condition(type_).status().as_str().to_string()),
+                )
+                .collect()
+        }))
+        .collect();
+
+    let widths: Vec<usize> = (0..=types.len())
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect();
+
+    let mut table = String::new();
+    for row in rows {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        table.push_str(line.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conditions::testing::{Dummy, DummySpec};
+
+    #[test]
+    fn aligns_columns() {
+        let mut short = Dummy::new("db", DummySpec {});
+        short.condition_mut("Ready").set_true();
+        let mut long = Dummy::new("frontend-canary", DummySpec {});
+        long.condition_mut("Available").set_false();
+
+        let table = render_condition_table(&[short, long], &["Ready", "Available"]);
+        assert_eq!(
+            table,
+            "NAME             READY    AVAILABLE\n\
+             db               True     Unknown\n\
+             frontend-canary  Unknown  False\n"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Fixtures shared by the tests of the conditions module.
+
+use std::fmt;
+use std::ops::DerefMut;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use k8s_openapi::api::apps::v1::{Deployment, DeploymentCondition, DeploymentStatus};
+use k8s_openapi::api::core::v1::{Node, NodeCondition, NodeStatus};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{Condition, Time};
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{ConditionReason, HasStatusConditions, list};
+
+#[derive(CustomResource, Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+#[kube(
+    group = "kube-observe.test",
+    version = "v1",
+    kind = "Dummy",
+    status = "DummyStatus"
+)]
+pub struct DummySpec {}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema)]
+pub struct DummyStatus {
+    pub conditions: Option<Vec<Condition>>,
+}
+
+impl HasStatusConditions for Dummy {
+    fn condition_opt(&self, type_: impl ToString) -> Option<Condition> {
+        let conditions = self
+            .status
+            .as_ref()
+            .and_then(|status| status.conditions.as_ref());
+        list::condition_opt(conditions, type_.to_string())
+    }
+
+    fn condition_types(&self) -> Vec<String> {
+        let conditions = self
+            .status
+            .as_ref()
+            .and_then(|status| status.conditions.as_ref());
+        list::condition_types(conditions)
+    }
+
+    fn conditions_iter(&self) -> impl Iterator<Item = Condition> {
+        let conditions = self
+            .status
+            .as_ref()
+            .and_then(|status| status.conditions.as_ref());
+        list::conditions_iter(conditions)
+    }
+
+    fn condition_mut(&mut self, type_: impl ToString) -> impl DerefMut<Target = Condition> {
+        let conditions = self
+            .status
+            .get_or_insert_with(DummyStatus::default)
+            .conditions
+            .get_or_insert_with(Vec::new);
+        list::condition_mut(conditions, type_.to_string())
+    }
+
+    fn conditions_mut(&mut self) -> impl DerefMut<Target = Vec<Condition>> {
+        self.status
+            .get_or_insert_with(DummyStatus::default)
+            .conditions
+            .get_or_insert_with(Vec::new)
+    }
+
+    fn remove_condition(&mut self, type_: impl ToString) -> Option<Condition> {
+        let conditions = self
+            .status
+            .as_mut()
+            .and_then(|status| status.conditions.as_mut());
+        list::remove_condition(conditions, type_.to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReadyReasons {
+    Yoyo,
+}
+
+impl ConditionReason for ReadyReasons {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ReadyReasons::Yoyo => "Yoyo",
+        }
+    }
+}
+
+impl FromStr for ReadyReasons {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Yoyo" => Ok(ReadyReasons::Yoyo),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for ReadyReasons {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+pub fn epoch() -> Time {
+    Time(DateTime::UNIX_EPOCH)
+}
+
+pub fn dummy() -> Dummy {
+    let mut dummy = Dummy::new("dummy", DummySpec {});
+    dummy.metadata.generation = Some(3);
+    dummy
+}
+
+/// A node with conditions of the given types and statuses.
+pub fn node(conditions: &[(&str, &str)]) -> Node {
+    Node {
+        status: Some(NodeStatus {
+            conditions: Some(
+                conditions
+                    .iter()
+                    .map(|(type_, status)| NodeCondition {
+                        type_: type_.to_string(),
+                        status: status.to_string(),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// A deployment with conditions of the given types, statuses, and reasons, whose messages read
+/// `"<type> is <status>"`.
+pub fn deployment(conditions: &[(&str, &str, &str)]) -> Deployment {
+    Deployment {
+        status: Some(DeploymentStatus {
+            conditions: Some(
+                conditions
+                    .iter()
+                    .map(|(type_, status, reason)| DeploymentCondition {
+                        type_: type_.to_string(),
+                        status: status.to_string(),
+                        reason: Some(reason.to_string()),
+                        message: Some(format!("{type_} is {status}")),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,53 @@
+use std::ops::{Deref, DerefMut};
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+
+use super::has_changed;
+
+/// A mutable reference to a condition that keeps its observed generation in sync with a resource.
+///
+/// It dereferences to the underlying [`Condition`], so every [`ConditionExt`](super::ConditionExt)
+/// setter works as usual. If the condition changed by the time it is dropped, its observed
+/// generation is set to the generation captured from the resource.
+pub struct TrackedCondition<G: DerefMut<Target = Condition>> {
+    condition: G,
+    original: Condition,
+    generation: Option<i64>,
+}
+
+impl<G: DerefMut<Target = Condition>> TrackedCondition<G> {
+    pub(crate) fn new(condition: G, generation: Option<i64>) -> Self {
+        Self {
+            original: condition.clone(),
+            condition,
+            generation,
+        }
+    }
+
+    /// The generation that is stamped on the condition if it changes.
+    pub fn generation(&self) -> Option<i64> {
+        self.generation
+    }
+}
+
+impl<G: DerefMut<Target = Condition>> Deref for TrackedCondition<G> {
+    type Target = Condition;
+
+    fn deref(&self) -> &Condition {
+        &self.condition
+    }
+}
+
+impl<G: DerefMut<Target = Condition>> DerefMut for TrackedCondition<G> {
+    fn deref_mut(&mut self) -> &mut Condition {
+        &mut self.condition
+    }
+}
+
+impl<G: DerefMut<Target = Condition>> Drop for TrackedCondition<G> {
+    fn drop(&mut self) {
+        if has_changed(&self.original, &self.condition) {
+            self.condition.observed_generation = self.generation;
+        }
+    }
+}
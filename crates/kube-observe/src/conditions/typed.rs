@@ -0,0 +1,288 @@
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{Condition, Time};
+
+use super::ConditionExt;
+use crate::clock;
+
+/// A resource-specific condition, such as a `PodCondition`, that can be viewed as a generic [`Condition`].
+pub(crate) trait TypedCondition: Sized {
+    /// The type of the condition.
+    fn type_(&self) -> &str;
+
+    /// Converts the condition into a generic [`Condition`].
+    fn project(&self) -> Condition;
+
+    /// Copies the fields of a generic [`Condition`] into the condition, leaving resource-specific
+    /// fields untouched.
+    fn write_back(&mut self, condition: &Condition);
+
+    /// Creates a condition from a generic [`Condition`].
+    fn from_condition(condition: &Condition) -> Self;
+}
+
+/// Returns the condition of the given type from a list of typed conditions, if present.
+pub(crate) fn condition_opt<C: TypedCondition>(
+    conditions: Option<&Vec<C>>,
+    type_: String,
+) -> Option<Condition> {
+    conditions?
+        .iter()
+        .find(|condition| condition.type_() == type_)
+        .map(TypedCondition::project)
+}
+
+/// Iterates over a list of typed conditions, converting them to generic [`Condition`]s one at a
+/// time.
+pub(crate) fn conditions_iter<C: TypedCondition>(
+    conditions: Option<&Vec<C>>,
+) -> impl Iterator<Item = Condition> {
+    conditions
+        .into_iter()
+        .flatten()
+        .map(TypedCondition::project)
+}
+
+/// Returns the types of a list of typed conditions.
+pub(crate) fn condition_types<C: TypedCondition>(conditions: Option<&Vec<C>>) -> Vec<String> {
+    conditions
+        .into_iter()
+        .flatten()
+        .map(|condition| condition.type_().to_string())
+        .collect()
+}
+
+/// Returns a generic view of the condition of the given type, inserting an `Unknown` condition if
+/// there is none.
+pub(crate) fn condition_mut<C: TypedCondition>(
+    conditions: &mut Vec<C>,
+    type_: String,
+) -> TypedConditionMut<'_, C> {
+    let index = match conditions
+        .iter()
+        .position(|condition| condition.type_() == type_)
+    {
+        Some(index) => index,
+        None => {
+            conditions.push(C::from_condition(&Condition::unknown(type_)));
+            conditions.len() - 1
+        }
+    };
+    TypedConditionMut::new(&mut conditions[index])
+}
+
+/// Returns a generic view of a whole list of typed conditions.
+pub(crate) fn conditions_mut<C: TypedCondition>(
+    conditions: &mut Vec<C>,
+) -> TypedConditionsMut<'_, C> {
+    TypedConditionsMut::new(conditions)
+}
+
+/// Removes the condition of the given type from a list of typed conditions, returning it if it was
+/// present.
+pub(crate) fn remove_condition<C: TypedCondition>(
+    conditions: Option<&mut Vec<C>>,
+    type_: String,
+) -> Option<Condition> {
+    let conditions = conditions?;
+    let index = conditions
+        .iter()
+        .position(|condition| condition.type_() == type_)?;
+    Some(conditions.remove(index).project())
+}
+
+/// Converts the optional transition time of a typed condition, defaulting to the current time.
+pub(crate) fn transition_time(time: Option<&Time>) -> Time {
+    time.cloned().unwrap_or_else(|| Time(clock::now()))
+}
+
+/// Converts a possibly empty string into an optional one, as typed conditions store them.
+pub(crate) fn non_empty(value: &str) -> Option<String> {
+    Some(value.to_string()).filter(|value| !value.is_empty())
+}
+
+/// A generic view of a typed condition that writes any changes back to it when dropped.
+pub(crate) struct TypedConditionMut<'a, C: TypedCondition> {
+    target: &'a mut C,
+    original: Condition,
+    condition: Condition,
+}
+
+impl<'a, C: TypedCondition> TypedConditionMut<'a, C> {
+    fn new(target: &'a mut C) -> Self {
+        let original = target.project();
+        Self {
+            condition: original.clone(),
+            original,
+            target,
+        }
+    }
+}
+
+impl<C: TypedCondition> Deref for TypedConditionMut<'_, C> {
+    type Target = Condition;
+
+    fn deref(&self) -> &Condition {
+        &self.condition
+    }
+}
+
+impl<C: TypedCondition> DerefMut for TypedConditionMut<'_, C> {
+    fn deref_mut(&mut self) -> &mut Condition {
+        &mut self.condition
+    }
+}
+
+impl<C: TypedCondition> Drop for TypedConditionMut<'_, C> {
+    fn drop(&mut self) {
+        if self.condition != self.original {
+            self.target.write_back(&self.condition);
+        }
+    }
+}
+
+/// A generic view of a list of typed conditions that writes any changes back to it when dropped.
+///
+/// Conditions that are still present keep their resource-specific fields, and are only written back
+/// if they changed.
+pub(crate) struct TypedConditionsMut<'a, C: TypedCondition> {
+    target: &'a mut Vec<C>,
+    original: Vec<Condition>,
+    conditions: Vec<Condition>,
+}
+
+impl<'a, C: TypedCondition> TypedConditionsMut<'a, C> {
+    fn new(target: &'a mut Vec<C>) -> Self {
+        let original: Vec<_> = target.iter().map(TypedCondition::project).collect();
+        Self {
+            conditions: original.clone(),
+            original,
+            target,
+        }
+    }
+}
+
+impl<C: TypedCondition> Deref for TypedConditionsMut<'_, C> {
+    type Target = Vec<Condition>;
+
+    fn deref(&self) -> &Vec<Condition> {
+        &self.conditions
+    }
+}
+
+impl<C: TypedCondition> DerefMut for TypedConditionsMut<'_, C> {
+    fn deref_mut(&mut self) -> &mut Vec<Condition> {
+        &mut self.conditions
+    }
+}
+
+impl<C: TypedCondition> Drop for TypedConditionsMut<'_, C> {
+    fn drop(&mut self) {
+        if self.conditions == self.original {
+            return;
+        }
+        let mut previous: Vec<_> = mem::take(self.target).into_iter().map(Some).collect();
+        for condition in &self.conditions {
+            let index = self
+                .original
+                .iter()
+                .enumerate()
+                .position(|(index, original)| {
+                    original.type_ == condition.type_ && previous[index].is_some()
+                });
+            let typed = match index {
+                Some(index) => {
+                    let mut typed = previous[index].take().expect("checked above");
+                    if self.original[index] != *condition {
+                        typed.write_back(condition);
+                    }
+                    typed
+                }
+                None => C::from_condition(condition),
+            };
+            self.target.push(typed);
+        }
+    }
+}
+
+/// Implements [`HasStatusConditions`](super::HasStatusConditions) for a resource that stores a list
+/// of [`TypedCondition`]s at `$status.$conditions`, where both fields are [`Option`]s.
+///
+/// Attributes such as doc comments before the resource type are applied to the generated impl.
+macro_rules! impl_has_status_conditions {
+    ($(#[$meta:meta])* $resource:ty, $status:ident.$conditions:ident) => {
+        $(#[$meta])*
+        impl $crate::conditions::HasStatusConditions for $resource {
+            fn condition_opt(
+                &self,
+                type_: impl ToString,
+            ) -> Option<::k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition> {
+                let conditions = self
+                    .$status
+                    .as_ref()
+                    .and_then(|status| status.$conditions.as_ref());
+                $crate::conditions::typed::condition_opt(conditions, type_.to_string())
+            }
+
+            fn condition_types(&self) -> Vec<String> {
+                let conditions = self
+                    .$status
+                    .as_ref()
+                    .and_then(|status| status.$conditions.as_ref());
+                $crate::conditions::typed::condition_types(conditions)
+            }
+
+            fn conditions_iter(
+                &self,
+            ) -> impl Iterator<Item = ::k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition>
+            {
+                let conditions = self
+                    .$status
+                    .as_ref()
+                    .and_then(|status| status.$conditions.as_ref());
+                $crate::conditions::typed::conditions_iter(conditions)
+            }
+
+            fn condition_mut(
+                &mut self,
+                type_: impl ToString,
+            ) -> impl ::std::ops::DerefMut<
+                Target = ::k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition,
+            > {
+                let conditions = self
+                    .$status
+                    .get_or_insert_with(Default::default)
+                    .$conditions
+                    .get_or_insert_with(Vec::new);
+                $crate::conditions::typed::condition_mut(conditions, type_.to_string())
+            }
+
+            fn conditions_mut(
+                &mut self,
+            ) -> impl ::std::ops::DerefMut<
+                Target = Vec<::k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition>,
+            > {
+                let conditions = self
+                    .$status
+                    .get_or_insert_with(Default::default)
+                    .$conditions
+                    .get_or_insert_with(Vec::new);
+                $crate::conditions::typed::conditions_mut(conditions)
+            }
+
+            fn remove_condition(
+                &mut self,
+                type_: impl ToString,
+            ) -> Option<::k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition> {
+                let conditions = self
+                    .$status
+                    .as_mut()
+                    .and_then(|status| status.$conditions.as_mut());
+                $crate::conditions::typed::remove_condition(conditions, type_.to_string())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_has_status_conditions;
@@ -0,0 +1,119 @@
+//! Recording condition transitions as kubernetes events.
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube::Resource;
+use kube::runtime::events::{Event, EventType, Recorder};
+
+use crate::conditions::has_changed;
+
+/// The reason used for events about conditions that do not have a reason of their own.
+const DEFAULT_REASON: &str = "ConditionChanged";
+
+/// The action reported for every condition transition event.
+const ACTION: &str = "UpdateCondition";
+
+/// Builds an event describing the transition of a condition from `previous` to `current`.
+///
+/// Returns `None` if the condition did not actually change, using the same rule as the setters of
+/// [`ConditionExt`](crate::ConditionExt).
+pub fn transition_event(previous: &Condition, current: &Condition) -> Option<Event> {
+    if !has_changed(previous, current) {
+        return None;
+    }
+
+    let mut note = format!(
+        "{}: {} -> {}",
+        current.type_, previous.status, current.status
+    );
+    if !current.reason.is_empty() {
+        note += &format!(" ({})", current.reason);
+    }
+    if !current.message.is_empty() {
+        note += &format!(": {}", current.message);
+    }
+
+    let reason = if current.reason.is_empty() {
+        DEFAULT_REASON
+    } else {
+        &current.reason
+    };
+    Some(Event {
+        type_: EventType::Normal,
+        reason: reason.to_string(),
+        note: Some(note),
+        action: ACTION.to_string(),
+        secondary: None,
+    })
+}
+
+/// Publishes an event about the resource describing the transition of one of its conditions from
+/// `previous` to `current`.
+///
+/// Nothing is published if the condition did not actually change. Returns whether an event was
+/// published.
+pub async fn record_transition<K>(
+    recorder: &Recorder,
+    resource: &K,
+    previous: &Condition,
+    current: &Condition,
+) -> Result<bool, kube::Error>
+where
+    K: Resource,
+    K::DynamicType: Default,
+{
+    let Some(event) = transition_event(previous, current) else {
+        return Ok(false);
+    };
+    let reference = resource.object_ref(&Default::default());
+    recorder.publish(&event, &reference).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConditionBuilder, ConditionExt, ConditionStatus};
+
+    fn ready() -> Condition {
+        ConditionBuilder::new()
+            .type_("Ready")
+            .status(ConditionStatus::False)
+            .build()
+    }
+
+    #[test]
+    fn describes_transition() {
+        let previous = ready();
+        let mut current = previous.clone();
+        current.set_all(ConditionStatus::True, "Reconciled", "all good");
+
+        let event = transition_event(&previous, &current).unwrap();
+        assert_eq!(event.type_, EventType::Normal);
+        assert_eq!(event.reason, "Reconciled");
+        assert_eq!(
+            event.note.as_deref(),
+            Some("Ready: False -> True (Reconciled): all good")
+        );
+    }
+
+    #[test]
+    fn falls_back_to_default_reason() {
+        let previous = ready();
+        let mut current = previous.clone();
+        current.set_true();
+
+        let event = transition_event(&previous, &current).unwrap();
+        assert_eq!(event.reason, DEFAULT_REASON);
+        assert_eq!(event.note.as_deref(), Some("Ready: False -> True"));
+    }
+
+    #[test]
+    fn no_event_without_transition() {
+        let previous = ready();
+        let mut current = previous.clone();
+        current.last_transition_time = previous.last_transition_time.clone();
+        current.set_false();
+
+        assert!(transition_event(&previous, &current).is_none());
+    }
+}
@@ -1,14 +1,40 @@
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
-}
+//! Helpers for managing conditions and events in kubernetes.
+
+pub mod clock;
+#[cfg(feature = "color")]
+pub mod color;
+pub mod conditions;
+#[cfg(feature = "runtime")]
+pub mod events;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "runtime")]
+pub mod patch;
+#[cfg(feature = "schemars")]
+pub mod schema;
+#[cfg(feature = "runtime")]
+pub mod wait;
+
+pub use conditions::{
+    ConditionAccessor, ConditionBuilder, ConditionError, ConditionExt, ConditionIterExt,
+    ConditionSet, ConditionStatus, DeploymentConditionExt, DisplayCondition, HasStatusConditions,
+    JobConditionExt, NodeConditionExt, PodConditionExt, TryHasStatusConditions, standard,
+};
+/// Derives [`HasStatusConditions`] for a struct that stores its conditions as a
+/// `Vec<Condition>`.
+///
+/// The conditions are read from `status.conditions` by default; use
+/// `#[conditions(path = "...")]` to point at another field. Every field along the path must be an
+/// [`Option`] whose contents implement [`Default`].
+#[cfg(feature = "derive")]
+pub use kube_observe_derive::HasStatusConditions;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Items used by the code generated by `kube-observe-derive`. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
+    pub use crate::conditions::list::{
+        condition_mut, condition_opt, condition_types, conditions_iter, remove_condition,
+    };
 }
@@ -0,0 +1,188 @@
+//! Exposing conditions as prometheus gauges.
+//!
+//! Every condition is exported as one series per status, with the value `1` for the status the
+//! condition is in and `0` for the others:
+//!
+//! ```text
+//! kube_observe_condition{kind="Deployment",namespace="default",name="web",type="Available",status="True"} 1
+//! kube_observe_condition{kind="Deployment",namespace="default",name="web",type="Available",status="False"} 0
+//! kube_observe_condition{kind="Deployment",namespace="default",name="web",type="Available",status="Unknown"} 0
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex};
+
+use prometheus::{GaugeVec, Opts, Registry};
+
+use crate::{ConditionExt, ConditionStatus, HasStatusConditions};
+
+const STATUSES: [ConditionStatus; 3] = [
+    ConditionStatus::True,
+    ConditionStatus::False,
+    ConditionStatus::Unknown,
+];
+
+/// Identifies the resource whose conditions are being observed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Labels {
+    pub kind: String,
+    pub namespace: String,
+    pub name: String,
+}
+
+/// The condition gauges registered with a registry.
+///
+/// Clones share the same gauges, so a single value can be handed to every reconciler.
+#[derive(Clone, Debug)]
+pub struct ConditionMetrics {
+    gauges: GaugeVec,
+    /// The condition types last observed on each resource, to delete the series of conditions that
+    /// have since been removed.
+    observed: Arc<Mutex<HashMap<Labels, BTreeSet<String>>>>,
+}
+
+impl ConditionMetrics {
+    /// Creates the condition gauges and registers them with the registry.
+    pub fn register(registry: &Registry) -> prometheus::Result<Self> {
+        let opts = Opts::new(
+            "kube_observe_condition",
+            "Whether a status condition of a resource is in the given status.",
+        );
+        let gauges = GaugeVec::new(opts, &["kind", "namespace", "name", "type", "status"])?;
+        registry.register(Box::new(gauges.clone()))?;
+        Ok(Self {
+            gauges,
+            observed: Arc::default(),
+        })
+    }
+
+    /// Updates the gauges with the conditions currently stored on the resource, deleting the series
+    /// of conditions it no longer has.
+    pub fn observe(&self, resource: &impl HasStatusConditions, labels: &Labels) {
+        let mut types = BTreeSet::new();
+        for condition in resource.conditions_iter() {
+            let status = condition.status();
+            for candidate in STATUSES {
+                let value = if candidate == status { 1.0 } else { 0.0 };
+                self.gauges
+                    .with_label_values(&label_values(labels, &condition.type_, candidate))
+                    .set(value);
+            }
+            types.insert(condition.type_);
+        }
+
+        let mut observed = self
+            .observed
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        let previous = observed.insert(labels.clone(), types.clone());
+        for type_ in previous.unwrap_or_default().difference(&types) {
+            self.remove(labels, type_);
+        }
+    }
+
+    /// Deletes the series of every condition of the resource, once it has been deleted.
+    pub fn forget(&self, labels: &Labels) {
+        let mut observed = self
+            .observed
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        for type_ in observed.remove(labels).unwrap_or_default() {
+            self.remove(labels, &type_);
+        }
+    }
+
+    fn remove(&self, labels: &Labels, type_: &str) {
+        for candidate in STATUSES {
+            // The series may never have been created, which is fine.
+            let _ = self
+                .gauges
+                .remove_label_values(&label_values(labels, type_, candidate));
+        }
+    }
+}
+
+fn label_values<'a>(labels: &'a Labels, type_: &'a str, status: ConditionStatus) -> [&'a str; 5] {
+    [
+        &labels.kind,
+        &labels.namespace,
+        &labels.name,
+        type_,
+        status.as_str(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::conditions::testing::dummy;
+
+    fn labels() -> Labels {
+        Labels {
+            kind: "Dummy".to_string(),
+            namespace: "metrics-test".to_string(),
+            name: "dummy".to_string(),
+        }
+    }
+
+    fn gathered(registry: &Registry) -> BTreeMap<String, f64> {
+        let mut values = BTreeMap::new();
+        for family in registry.gather() {
+            for metric in family.get_metric() {
+                let labels: BTreeMap<_, _> = metric
+                    .get_label()
+                    .iter()
+                    .map(|label| (label.name(), label.value()))
+                    .collect();
+                let key = format!("{}={}", labels["type"], labels["status"]);
+                values.insert(key, metric.get_gauge().get_value());
+            }
+        }
+        values
+    }
+
+    #[test]
+    fn exports_every_status() {
+        let registry = Registry::new();
+        let metrics = ConditionMetrics::register(&registry).unwrap();
+
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+        dummy.condition_mut("Synced").set_unknown();
+        metrics.observe(&dummy, &labels());
+
+        let values = gathered(&registry);
+        assert_eq!(values.len(), 6);
+        assert_eq!(values["Ready=True"], 1.0);
+        assert_eq!(values["Ready=False"], 0.0);
+        assert_eq!(values["Synced=Unknown"], 1.0);
+        assert_eq!(values["Synced=True"], 0.0);
+
+        let other = Registry::new();
+        ConditionMetrics::register(&other).unwrap();
+        assert!(gathered(&other).is_empty());
+    }
+
+    #[test]
+    fn removes_series_of_removed_conditions() {
+        let registry = Registry::new();
+        let metrics = ConditionMetrics::register(&registry).unwrap();
+
+        let mut dummy = dummy();
+        dummy.condition_mut("Ready").set_true();
+        dummy.condition_mut("Synced").set_true();
+        metrics.observe(&dummy, &labels());
+        assert_eq!(gathered(&registry).len(), 6);
+
+        dummy.remove_condition("Synced");
+        metrics.observe(&dummy, &labels());
+        let values = gathered(&registry);
+        assert_eq!(values.len(), 3);
+        assert_eq!(values["Ready=True"], 1.0);
+
+        metrics.forget(&labels());
+        assert!(gathered(&registry).is_empty());
+    }
+}
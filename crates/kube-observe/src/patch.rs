@@ -0,0 +1,244 @@
+//! Building status patches from the conditions of a resource.
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube::Resource;
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::HasStatusConditions;
+
+/// Builds a JSON merge patch that sets `status.conditions` to the current conditions of the
+/// resource.
+///
+/// The conditions are serialized exactly as the resource stores them, so fields specific to its
+/// condition type, such as the `lastUpdateTime` of Deployment conditions, are kept and fields it
+/// leaves unset stay unset. A merge patch replaces the whole list, so any condition missing from
+/// the resource is removed on the server.
+///
+/// ```no_run
+/// # async fn reconcile(api: kube::Api<k8s_openapi::api::apps::v1::Deployment>) -> kube::Result<()> {
+/// use kube::api::{Patch, PatchParams};
+/// use kube_observe::patch::status_conditions_patch;
+///
+/// let deployment = api.get_status("my-deployment").await?;
+/// let patch = status_conditions_patch(&deployment);
+/// api.patch_status("my-deployment", &PatchParams::default(), &Patch::Merge(patch))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn status_conditions_patch(resource: &(impl HasStatusConditions + Serialize)) -> Value {
+    json!({
+        "status": {
+            "conditions": stored_conditions(resource),
+        },
+    })
+}
+
+/// Builds a strategic merge patch that sends only the given conditions.
+///
+/// The built-in resources declare `type` as the merge key of their conditions, so the apiserver
+/// merges each sent condition into the stored list by type and leaves the others alone. This keeps
+/// controllers that own different conditions from overwriting each other. Pass only the conditions
+/// that changed, for instance those reported by
+/// [`diff_conditions`](crate::conditions::diff_conditions).
+///
+/// Custom resources do not support strategic merge patches; use
+/// [`status_conditions_apply_patch`] with a schema that marks the list as a map instead.
+///
+/// ```no_run
+/// # async fn reconcile(api: kube::Api<k8s_openapi::api::core::v1::Pod>) -> kube::Result<()> {
+/// use kube::api::{Patch, PatchParams};
+/// use kube_observe::patch::status_conditions_strategic_patch;
+/// use kube_observe::{ConditionExt, HasStatusConditions};
+///
+/// let mut pod = api.get_status("my-pod").await?;
+/// pod.condition_mut("example.com/feature-1").set_true();
+/// let patch = status_conditions_strategic_patch([&pod.condition("example.com/feature-1")]);
+/// api.patch_status("my-pod", &PatchParams::default(), &Patch::Strategic(patch))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn status_conditions_strategic_patch<'a>(
+    changed: impl IntoIterator<Item = &'a Condition>,
+) -> Value {
+    let conditions: Vec<_> = changed.into_iter().collect();
+    json!({
+        "status": {
+            "conditions": conditions,
+        },
+    })
+}
+
+/// Builds a server-side apply patch that owns only `status.conditions` of the resource.
+///
+/// The patch carries the `apiVersion`, `kind`, name, and namespace of the resource alongside its
+/// conditions, as apply patches must identify the object they target. As with
+/// [`status_conditions_patch`], the conditions are serialized exactly as the resource stores them.
+/// Whether the conditions are merged by `type` is decided by the schema of the resource, not by
+/// the patch: the built-in resources and CRDs that mark the list with
+/// `x-kubernetes-list-type: map` and `x-kubernetes-list-map-keys: [type]` merge per condition,
+/// while others replace the whole list.
+/// The field manager that owns the conditions is the one passed to
+/// [`PatchParams::apply`](kube::api::PatchParams::apply).
+///
+/// ```no_run
+/// # async fn reconcile(api: kube::Api<k8s_openapi::api::apps::v1::Deployment>) -> kube::Result<()> {
+/// use kube::api::{Patch, PatchParams};
+/// use kube_observe::patch::status_conditions_apply_patch;
+///
+/// let deployment = api.get_status("my-deployment").await?;
+/// let patch = status_conditions_apply_patch(&deployment);
+/// let params = PatchParams::apply("my-controller");
+/// api.patch_status("my-deployment", &params, &Patch::Apply(patch))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn status_conditions_apply_patch<K>(resource: &K) -> Value
+where
+    K: Resource + HasStatusConditions + Serialize,
+    K::DynamicType: Default,
+{
+    let dynamic_type = K::DynamicType::default();
+    let mut metadata = json!({ "name": resource.meta().name });
+    if let Some(namespace) = &resource.meta().namespace {
+        metadata["namespace"] = json!(namespace);
+    }
+    json!({
+        "apiVersion": K::api_version(&dynamic_type),
+        "kind": K::kind(&dynamic_type),
+        "metadata": metadata,
+        "status": {
+            "conditions": stored_conditions(resource),
+        },
+    })
+}
+
+/// The `status.conditions` of the resource as it serializes them, or an empty list if it has none.
+fn stored_conditions(resource: &impl Serialize) -> Value {
+    let mut resource = serde_json::to_value(resource).expect("resources serialize to JSON");
+    resource
+        .pointer_mut("/status/conditions")
+        .map_or_else(|| json!([]), Value::take)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use k8s_openapi::api::apps::v1::{Deployment, DeploymentCondition, DeploymentStatus};
+    use k8s_openapi::api::core::v1::{Pod, PodCondition, PodStatus};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+    use super::*;
+    use crate::ConditionExt;
+    use crate::conditions::testing::dummy;
+    use crate::conditions::{ConditionDiff, diff_conditions};
+
+    #[test]
+    fn merge_patch_contains_conditions() {
+        let pod = Pod {
+            status: Some(PodStatus {
+                conditions: Some(vec![PodCondition {
+                    type_: "Ready".to_string(),
+                    status: "True".to_string(),
+                    last_transition_time: Some(Time(DateTime::UNIX_EPOCH)),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            status_conditions_patch(&pod),
+            json!({
+                "status": {
+                    "conditions": [{
+                        "type": "Ready",
+                        "status": "True",
+                        "lastTransitionTime": "1970-01-01T00:00:00Z",
+                    }],
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn merge_patch_keeps_typed_fields() {
+        let deployment = Deployment {
+            status: Some(DeploymentStatus {
+                conditions: Some(vec![DeploymentCondition {
+                    type_: "Progressing".to_string(),
+                    status: "True".to_string(),
+                    last_update_time: Some(Time(DateTime::UNIX_EPOCH)),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            status_conditions_patch(&deployment),
+            json!({
+                "status": {
+                    "conditions": [{
+                        "type": "Progressing",
+                        "status": "True",
+                        "lastUpdateTime": "1970-01-01T00:00:00Z",
+                    }],
+                },
+            })
+        );
+        assert_eq!(
+            status_conditions_apply_patch(&deployment)["status"]["conditions"],
+            status_conditions_patch(&deployment)["status"]["conditions"]
+        );
+        assert_eq!(
+            status_conditions_patch(&Deployment::default()),
+            json!({ "status": { "conditions": [] } })
+        );
+    }
+
+    #[test]
+    fn strategic_patch_contains_only_changed_conditions() {
+        let mut dummy = dummy();
+        dummy.condition_mut("Available").set_true();
+        dummy.condition_mut("Ready").set_false();
+        let previous = dummy.clone();
+        dummy.condition_mut("Ready").set_true();
+
+        let changed: Vec<_> = diff_conditions(&previous, &dummy)
+            .into_iter()
+            .filter_map(|diff| match diff {
+                ConditionDiff::Changed { current, .. } => Some(current),
+                _ => None,
+            })
+            .collect();
+        let patch = status_conditions_strategic_patch(&changed);
+
+        let conditions = patch["status"]["conditions"].as_array().unwrap();
+        assert_eq!(conditions.len(), 1);
+        assert_eq!(conditions[0]["type"], "Ready");
+        assert_eq!(conditions[0]["status"], "True");
+    }
+
+    #[test]
+    fn apply_patch_identifies_resource() {
+        let mut dummy = dummy();
+        dummy.metadata.namespace = Some("default".to_string());
+        dummy.condition_mut("Ready").set_true();
+
+        let patch = status_conditions_apply_patch(&dummy);
+        assert_eq!(patch["apiVersion"], "kube-observe.test/v1");
+        assert_eq!(patch["kind"], "Dummy");
+        assert_eq!(
+            patch["metadata"],
+            json!({ "name": "dummy", "namespace": "default" })
+        );
+        assert_eq!(patch["status"]["conditions"][0]["type"], "Ready");
+        assert_eq!(patch["status"]["conditions"][0]["status"], "True");
+    }
+}
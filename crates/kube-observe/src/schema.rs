@@ -0,0 +1,74 @@
+//! JSON schemas for the conditions of custom resources.
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use schemars::r#gen::SchemaGenerator;
+use schemars::schema::{ArrayValidation, InstanceType, Schema, SchemaObject};
+use serde_json::json;
+
+/// The schema of a list of [`Condition`]s, marked as a map keyed by `type`.
+///
+/// The `x-kubernetes-list-type: map` and `x-kubernetes-list-map-keys: [type]` markers make the
+/// apiserver merge conditions by type in server-side apply, rather than replacing the whole list.
+/// Use it on the conditions field of a custom resource status:
+///
+/// ```
+/// use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+/// use schemars::JsonSchema;
+///
+/// #[derive(JsonSchema)]
+/// struct MyStatus {
+///     #[schemars(schema_with = "kube_observe::schema::conditions_schema")]
+///     conditions: Option<Vec<Condition>>,
+/// }
+/// ```
+pub fn conditions_schema(generator: &mut SchemaGenerator) -> Schema {
+    let mut schema = SchemaObject {
+        instance_type: Some(InstanceType::Array.into()),
+        array: Some(Box::new(ArrayValidation {
+            items: Some(generator.subschema_for::<Condition>().into()),
+            ..Default::default()
+        })),
+        ..Default::default()
+    };
+    schema
+        .extensions
+        .insert("x-kubernetes-list-type".to_string(), json!("map"));
+    schema
+        .extensions
+        .insert("x-kubernetes-list-map-keys".to_string(), json!(["type"]));
+    schema.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::JsonSchema;
+    use schemars::r#gen::SchemaSettings;
+
+    use super::*;
+
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct Status {
+        #[schemars(schema_with = "conditions_schema")]
+        conditions: Option<Vec<Condition>>,
+    }
+
+    #[test]
+    fn marks_conditions_as_map() {
+        let mut generator = SchemaSettings::openapi3()
+            .with(|settings| settings.inline_subschemas = true)
+            .into_generator();
+        let schema = serde_json::to_value(generator.root_schema_for::<Status>()).unwrap();
+
+        let conditions = &schema["properties"]["conditions"];
+        assert_eq!(conditions["type"], "array");
+        assert_eq!(conditions["x-kubernetes-list-type"], "map");
+        assert_eq!(conditions["x-kubernetes-list-map-keys"], json!(["type"]));
+        assert!(
+            conditions["items"]["required"]
+                .as_array()
+                .unwrap()
+                .contains(&json!("type"))
+        );
+    }
+}
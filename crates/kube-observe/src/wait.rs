@@ -0,0 +1,326 @@
+//! Waiting for the conditions of a resource to reach a desired state.
+
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::pin::pin;
+use std::time::Duration;
+
+use futures::TryStreamExt;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Condition;
+use kube::runtime::{WatchStreamExt, watcher};
+use kube::{Api, Resource};
+use serde::de::DeserializeOwned;
+
+use crate::HasStatusConditions;
+
+/// An error raised while waiting for a condition.
+#[derive(Debug)]
+pub enum WaitError {
+    /// The condition was not met before the timeout elapsed.
+    Timeout,
+    /// Watching the resource failed.
+    Watcher(watcher::Error),
+}
+
+impl fmt::Display for WaitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WaitError::Timeout => f.write_str("timed out waiting for condition"),
+            WaitError::Watcher(_) => f.write_str("failed to watch resource"),
+        }
+    }
+}
+
+impl Error for WaitError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WaitError::Timeout => None,
+            WaitError::Watcher(error) => Some(error),
+        }
+    }
+}
+
+impl From<watcher::Error> for WaitError {
+    fn from(error: watcher::Error) -> Self {
+        WaitError::Watcher(error)
+    }
+}
+
+/// Watches the named resource until `predicate` holds for its condition of the given type.
+///
+/// Resolves to the first version of the resource that satisfies the predicate. A missing condition
+/// is passed to the predicate as an `Unknown` condition, like [`HasStatusConditions::condition`].
+///
+/// ```no_run
+/// # async fn example(
+/// #     api: kube::Api<k8s_openapi::api::core::v1::Pod>,
+/// # ) -> Result<(), kube_observe::wait::WaitError> {
+/// use std::time::Duration;
+///
+/// use kube_observe::ConditionExt;
+/// use kube_observe::wait::wait_for_condition;
+///
+/// let pod = wait_for_condition(
+///     &api,
+///     "my-pod",
+///     "Ready",
+///     |c| c.is_true(),
+///     Duration::from_secs(60),
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn wait_for_condition<K>(
+    api: &Api<K>,
+    name: &str,
+    type_: impl ToString,
+    predicate: impl Fn(&Condition) -> bool,
+    timeout: Duration,
+) -> Result<K, WaitError>
+where
+    K: HasStatusConditions + Resource + Clone + DeserializeOwned + Debug + Send + 'static,
+    K::DynamicType: Default,
+{
+    let type_ = type_.to_string();
+    wait_for(
+        api,
+        name,
+        |object: &K| predicate(&object.condition(&type_)),
+        timeout,
+    )
+    .await
+}
+
+/// A predicate over the condition of a given type, for [`wait_for_conditions`].
+pub type ConditionPredicate<'a> = (&'a str, Box<dyn Fn(&Condition) -> bool + 'a>);
+
+/// Watches the named resource until every predicate holds for its condition of the given type.
+///
+/// All predicates are checked against the same version of the resource, so conditions that were
+/// each satisfied at some point but never at the same time do not count. Missing conditions are
+/// passed to the predicates as `Unknown` conditions.
+///
+/// ```no_run
+/// # async fn example(
+/// #     api: kube::Api<k8s_openapi::api::apps::v1::Deployment>,
+/// # ) -> Result<(), kube_observe::wait::WaitError> {
+/// use std::time::Duration;
+///
+/// use kube_observe::ConditionExt;
+/// use kube_observe::wait::wait_for_conditions;
+///
+/// let deployment = wait_for_conditions(
+///     &api,
+///     "my-deployment",
+///     &[
+///         ("Available", Box::new(|c| c.is_true())),
+///         ("Progressing", Box::new(|c| c.is_true())),
+///     ],
+///     Duration::from_secs(60),
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn wait_for_conditions<K>(
+    api: &Api<K>,
+    name: &str,
+    predicates: &[ConditionPredicate<'_>],
+    timeout: Duration,
+) -> Result<K, WaitError>
+where
+    K: HasStatusConditions + Resource + Clone + DeserializeOwned + Debug + Send + 'static,
+    K::DynamicType: Default,
+{
+    wait_for(
+        api,
+        name,
+        |object: &K| {
+            predicates
+                .iter()
+                .all(|(type_, predicate)| predicate(&object.condition(type_)))
+        },
+        timeout,
+    )
+    .await
+}
+
+/// Watches the named resource until `predicate` holds for a single version of it.
+pub(crate) async fn wait_for<K>(
+    api: &Api<K>,
+    name: &str,
+    predicate: impl Fn(&K) -> bool,
+    timeout: Duration,
+) -> Result<K, WaitError>
+where
+    K: Resource + Clone + DeserializeOwned + Debug + Send + 'static,
+    K::DynamicType: Default,
+{
+    let config = watcher::Config::default().fields(&format!("metadata.name={name}"));
+    let mut objects = pin!(watcher(api.clone(), config).applied_objects());
+    let wait = async {
+        while let Some(object) = objects.try_next().await? {
+            if predicate(&object) {
+                return Ok(object);
+            }
+        }
+        Err(WaitError::Timeout)
+    };
+    tokio::time::timeout(timeout, wait)
+        .await
+        .unwrap_or(Err(WaitError::Timeout))
+}
+
+#[cfg(test)]
+mod tests {
+    use http::{Request, Response};
+    use k8s_openapi::api::core::v1::{Pod, PodCondition, PodStatus};
+    use kube::Client;
+    use kube::client::Body;
+    use serde_json::json;
+
+    use super::*;
+    use crate::conditions::ConditionExt;
+
+    /// Creates a client backed by a fake API server that lists `initial` and then streams
+    /// `updates` as watch events.
+    fn mock_client<K: serde::Serialize + Send + 'static>(initial: K, updates: Vec<K>) -> Client {
+        let (service, mut handle) = tower_test::mock::pair::<Request<Body>, Response<Body>>();
+        tokio::spawn(async move {
+            let mut updates = Some(updates);
+            while let Some((request, send)) = handle.next_request().await {
+                let query = request.uri().query().unwrap_or_default();
+                let body = if query.contains("watch=true") {
+                    match updates.take() {
+                        Some(updates) => updates
+                            .iter()
+                            .map(|object| {
+                                json!({ "type": "MODIFIED", "object": object }).to_string() + "\n"
+                            })
+                            .collect::<String>(),
+                        None => std::future::pending().await,
+                    }
+                } else {
+                    json!({
+                        "apiVersion": "v1",
+                        "kind": "List",
+                        "metadata": { "resourceVersion": "1" },
+                        "items": [&initial],
+                    })
+                    .to_string()
+                };
+                send.send_response(Response::new(Body::from(body.into_bytes())));
+            }
+        });
+        Client::new(service, "default")
+    }
+
+    fn pod(version: &str, conditions: &[(&str, &str)]) -> Pod {
+        let mut pod = Pod {
+            status: Some(PodStatus {
+                conditions: Some(
+                    conditions
+                        .iter()
+                        .map(|(type_, status)| PodCondition {
+                            type_: type_.to_string(),
+                            status: status.to_string(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        pod.metadata.name = Some("pod".to_string());
+        pod.metadata.resource_version = Some(version.to_string());
+        pod
+    }
+
+    #[tokio::test]
+    async fn resolves_once_condition_is_met() {
+        let client = mock_client(
+            pod("1", &[("Ready", "False")]),
+            vec![
+                pod("2", &[("Ready", "False")]),
+                pod("3", &[("Ready", "True")]),
+            ],
+        );
+        let api = Api::<Pod>::namespaced(client, "default");
+
+        let pod = wait_for_condition(
+            &api,
+            "pod",
+            "Ready",
+            |c| c.is_true(),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(pod.metadata.resource_version.as_deref(), Some("3"));
+    }
+
+    #[tokio::test]
+    async fn waits_for_all_conditions_in_one_version() {
+        let client = mock_client(
+            pod("1", &[("Ready", "False"), ("Initialized", "False")]),
+            vec![
+                pod("2", &[("Ready", "True"), ("Initialized", "False")]),
+                pod("3", &[("Ready", "False"), ("Initialized", "True")]),
+                pod("4", &[("Ready", "True"), ("Initialized", "True")]),
+            ],
+        );
+        let api = Api::<Pod>::namespaced(client, "default");
+
+        let pod = wait_for_conditions(
+            &api,
+            "pod",
+            &[
+                ("Ready", Box::new(|c| c.is_true())),
+                ("Initialized", Box::new(|c| c.is_true())),
+            ],
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(pod.metadata.resource_version.as_deref(), Some("4"));
+    }
+
+    #[tokio::test]
+    async fn partial_satisfaction_across_versions_times_out() {
+        let client = mock_client(
+            pod("1", &[("Ready", "True"), ("Initialized", "False")]),
+            vec![pod("2", &[("Ready", "False"), ("Initialized", "True")])],
+        );
+        let api = Api::<Pod>::namespaced(client, "default");
+
+        let result = wait_for_conditions(
+            &api,
+            "pod",
+            &[
+                ("Ready", Box::new(|c| c.is_true())),
+                ("Initialized", Box::new(|c| c.is_true())),
+            ],
+            Duration::from_millis(100),
+        )
+        .await;
+        assert!(matches!(result, Err(WaitError::Timeout)));
+    }
+
+    #[tokio::test]
+    async fn times_out_if_condition_is_never_met() {
+        let client = mock_client(pod("1", &[("Ready", "False")]), vec![]);
+        let api = Api::<Pod>::namespaced(client, "default");
+
+        let result = wait_for_condition(
+            &api,
+            "pod",
+            "Ready",
+            |c| c.is_true(),
+            Duration::from_millis(100),
+        )
+        .await;
+        assert!(matches!(result, Err(WaitError::Timeout)));
+    }
+}